@@ -25,6 +25,10 @@ pub struct ArmyUnitLabel {
 #[derive(Component, Default)]
 pub struct HasActedThisTurn;
 
+/// The army loots the province it occupies when the turn is processed.
+#[derive(Component, Default, Debug)]
+pub struct LootOrder;
+
 #[derive(Component, Debug)]
 pub struct PendingMove {
     pub target_province: Entity,
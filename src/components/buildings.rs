@@ -33,7 +33,7 @@ impl BuildingType {
 
     pub fn description(&self) -> &'static str {
         match self {
-            BuildingType::Farm => "Increases population growth",
+            BuildingType::Farm => "Increases population growth and capacity",
             BuildingType::Mine => "Increases province income",
             BuildingType::Barracks => "Allows recruiting troops",
         }
@@ -1,3 +1,6 @@
+use crate::components::culture::{Culture, Religion};
+use crate::components::personality::Personality;
+use crate::misc::economy;
 use bevy::platform::collections::HashMap;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -9,10 +12,31 @@ pub struct Country {
     pub color: Color,
     pub owned_provinces: Vec<u32>,
     pub gold: u32,
+    pub tax_rate: f32,
+    pub culture: Option<Culture>,
+    pub religion: Option<Religion>,
     pub flag: Option<Handle<Image>>,
     pub flag_path: Option<String>,
 }
 
+pub const DEFAULT_TAX_RATE: f32 = 0.2;
+pub const MAX_TAX_RATE: f32 = 0.5;
+
+impl Country {
+    pub fn tax_income_multiplier(&self) -> f32 {
+        economy::tax_income_multiplier(self.tax_rate)
+    }
+
+    pub fn tax_growth_modifier(&self) -> f32 {
+        economy::tax_growth_modifier(self.tax_rate)
+    }
+
+    /// Chance that a random event is a tax revolt rather than another event.
+    pub fn tax_revolt_chance(&self) -> f64 {
+        (0.5 + (self.tax_rate - DEFAULT_TAX_RATE) as f64 * 1.5).clamp(0.2, 0.95)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CountryDef {
     pub id: u32,
@@ -22,6 +46,16 @@ pub struct CountryDef {
     pub gold: u32,
     pub owned_provinces: Vec<u32>,
     pub flag_path: Option<String>,
+    #[serde(default)]
+    pub culture: Option<Culture>,
+    #[serde(default)]
+    pub religion: Option<Religion>,
+    /// Provinces of other countries this one claims from the start.
+    #[serde(default)]
+    pub claims: Vec<u32>,
+    /// How the country behaves while the AI runs it.
+    #[serde(default)]
+    pub personality: Personality,
 }
 
 mod color_def {
@@ -54,6 +88,7 @@ pub struct Relations {
 pub enum Relation {
     Peace,
     War,
+    Alliance,
 }
 
 impl Relations {
@@ -64,6 +99,13 @@ impl Relations {
     pub fn set(&mut self, country: Entity, relation: Relation) {
         self.relations.insert(country, relation);
     }
+
+    pub fn allies(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.relations
+            .iter()
+            .filter(|&(_, &relation)| relation == Relation::Alliance)
+            .map(|(&country, _)| country)
+    }
 }
 
 #[derive(Event, Message)]
@@ -73,5 +115,48 @@ pub struct DiplomacyChanged {
     pub new_relation: Relation,
 }
 
+/// Wrongs other countries have done to this one, by wrongdoer. They fade over time.
+#[derive(Component, Default, Debug, Clone)]
+pub struct Grievances {
+    pub against: HashMap<Entity, f32>,
+}
+
+impl Grievances {
+    pub fn get(&self, country: Entity) -> f32 {
+        self.against.get(&country).copied().unwrap_or(0.0)
+    }
+
+    pub fn add(&mut self, country: Entity, amount: f32) {
+        *self.against.entry(country).or_insert(0.0) += amount;
+    }
+
+    pub fn fade(&mut self, amount: f32) {
+        for grievance in self.against.values_mut() {
+            *grievance -= amount;
+        }
+        self.against.retain(|_, grievance| *grievance > 0.0);
+    }
+
+    /// The country this one resents the most, if any.
+    pub fn worst(&self) -> Option<(Entity, f32)> {
+        self.against
+            .iter()
+            .map(|(&country, &grievance)| (country, grievance))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
 #[derive(Component)]
 pub struct AIControlled;
+
+/// Id reserved for the rebel faction, which is at war with every country.
+pub const REBEL_COUNTRY_ID: u32 = 0;
+
+#[derive(Component)]
+pub struct RebelFaction;
+
+/// Marks a country as the subject of another: it pays tribute and fights its overlord's wars.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct VassalOf {
+    pub overlord: Entity,
+}
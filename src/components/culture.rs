@@ -0,0 +1,95 @@
+// components/culture.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Culture {
+    English,
+    Scottish,
+    Irish,
+    Welsh,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CultureGroup {
+    Anglo,
+    Gaelic,
+    Brythonic,
+}
+
+impl Culture {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Culture::English => "English",
+            Culture::Scottish => "Scottish",
+            Culture::Irish => "Irish",
+            Culture::Welsh => "Welsh",
+        }
+    }
+
+    pub fn group(&self) -> CultureGroup {
+        match self {
+            Culture::English => CultureGroup::Anglo,
+            Culture::Scottish | Culture::Irish => CultureGroup::Gaelic,
+            Culture::Welsh => CultureGroup::Brythonic,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Culture::English => Color::srgb(0.85, 0.35, 0.3),
+            Culture::Scottish => Color::srgb(0.3, 0.45, 0.85),
+            Culture::Irish => Color::srgb(0.3, 0.75, 0.35),
+            Culture::Welsh => Color::srgb(0.9, 0.8, 0.3),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Religion {
+    Catholic,
+    Anglican,
+    Presbyterian,
+}
+
+impl Religion {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Religion::Catholic => "Catholic",
+            Religion::Anglican => "Anglican",
+            Religion::Presbyterian => "Presbyterian",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Religion::Catholic => Color::srgb(0.95, 0.85, 0.4),
+            Religion::Anglican => Color::srgb(0.7, 0.3, 0.6),
+            Religion::Presbyterian => Color::srgb(0.35, 0.55, 0.75),
+        }
+    }
+}
+
+/// How close a province's culture is to its ruler's.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CultureMatch {
+    Same,
+    SameGroup,
+    Foreign,
+}
+
+impl CultureMatch {
+    /// Provinces or rulers without a culture never count as foreign.
+    pub fn between(province: Option<Culture>, ruler: Option<Culture>) -> Self {
+        match (province, ruler) {
+            (Some(a), Some(b)) if a == b => CultureMatch::Same,
+            (Some(a), Some(b)) if a.group() == b.group() => CultureMatch::SameGroup,
+            (Some(_), Some(_)) => CultureMatch::Foreign,
+            _ => CultureMatch::Same,
+        }
+    }
+}
+
+pub fn religion_differs(province: Option<Religion>, ruler: Option<Religion>) -> bool {
+    matches!((province, ruler), (Some(a), Some(b)) if a != b)
+}
@@ -0,0 +1,103 @@
+// components/debt.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub const LOAN_AMOUNT: u32 = 500;
+pub const LOAN_DURATION: u32 = 10;
+pub const MAX_LOANS: usize = 3;
+
+const BASE_INTEREST: f32 = 0.2;
+const INTEREST_PER_LOAN: f32 = 0.1;
+/// Share of a missed installment added on top of what is owed.
+const LATE_PENALTY: f32 = 0.25;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Loan {
+    pub principal: u32,
+    pub interest_rate: f32,
+    pub remaining: u32,
+    pub payment: u32,
+}
+
+impl Loan {
+    fn new(principal: u32, interest_rate: f32) -> Self {
+        let owed = (principal as f32 * (1.0 + interest_rate)).ceil() as u32;
+
+        Self {
+            principal,
+            interest_rate,
+            remaining: owed,
+            payment: owed.div_ceil(LOAN_DURATION),
+        }
+    }
+
+    pub fn next_payment(&self) -> u32 {
+        self.payment.min(self.remaining)
+    }
+}
+
+#[derive(Component, Default, Clone, Debug, Deserialize, Serialize)]
+pub struct Debt {
+    pub loans: Vec<Loan>,
+    /// Missed installments since the country was last free of debt.
+    pub missed_payments: u32,
+}
+
+/// What happened when a country's installments came due.
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct PaymentOutcome {
+    pub paid: u32,
+    pub missed: u32,
+}
+
+impl Debt {
+    pub fn total_owed(&self) -> u32 {
+        self.loans.iter().map(|loan| loan.remaining).sum()
+    }
+
+    pub fn payment_due(&self) -> u32 {
+        self.loans.iter().map(Loan::next_payment).sum()
+    }
+
+    /// Interest on the next loan; every open loan makes credit dearer.
+    pub fn interest_rate(&self) -> f32 {
+        BASE_INTEREST + INTEREST_PER_LOAN * self.loans.len() as f32
+    }
+
+    /// A country that has missed a payment gets no credit until its debt is repaid.
+    pub fn can_borrow(&self) -> bool {
+        self.loans.len() < MAX_LOANS && self.missed_payments == 0
+    }
+
+    /// Adds a loan of `amount` at the current interest rate. The caller credits the gold.
+    pub fn take_loan(&mut self, amount: u32) {
+        self.loans.push(Loan::new(amount, self.interest_rate()));
+    }
+
+    /// Pays every installment that `gold` can cover; the rest are missed and grow by a late penalty.
+    pub fn pay_installments(&mut self, gold: &mut u32) -> PaymentOutcome {
+        let mut outcome = PaymentOutcome::default();
+
+        for loan in &mut self.loans {
+            let due = loan.next_payment();
+
+            if *gold >= due {
+                *gold -= due;
+                loan.remaining -= due;
+                outcome.paid += due;
+            } else {
+                loan.remaining += (due as f32 * LATE_PENALTY).ceil() as u32;
+                outcome.missed += 1;
+            }
+        }
+
+        self.loans.retain(|loan| loan.remaining > 0);
+        self.missed_payments += outcome.missed;
+
+        if self.loans.is_empty() {
+            self.missed_payments = 0;
+        }
+
+        outcome
+    }
+}
@@ -1,17 +1,29 @@
 // components/events.rs
 use bevy::prelude::*;
 use rand::prelude::*;
+use std::collections::VecDeque;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EventType {
     ArmyDesertion,
     TaxRevolt,
+    DebtCrisis,
+    CallToArms,
+    ClaimDiscovered,
+}
+
+/// The events that can strike at random. The others are raised by something
+/// happening in the game, such as a missed loan payment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RandomEvent {
+    ArmyDesertion,
+    TaxRevolt,
 }
 
 #[derive(Debug, Clone)]
 pub struct EventOption {
     pub description: String,
-    pub effect: EventEffect,
+    pub effects: Vec<EventEffect>,
 }
 
 #[derive(Debug, Clone)]
@@ -21,6 +33,10 @@ pub enum EventEffect {
     LoseGold(u32),
     GainGold(u32),
     LosePopulation(f32),
+    ChangeUnrest(f32),
+    JoinWar { ally: Entity, enemy: Entity },
+    RefuseCallToArms { ally: Entity },
+    GainClaim { province: Entity },
 }
 
 #[derive(Debug, Clone)]
@@ -32,47 +48,110 @@ pub struct GameEvent {
 }
 
 impl GameEvent {
-    pub fn generate_random() -> Self {
+    pub fn generate_random(tax_revolt_chance: f64) -> Self {
         let mut rng = rand::rng();
-        let event_type = if rng.random_bool(0.5) {
-            EventType::ArmyDesertion
+        let random_event = if rng.random_bool(tax_revolt_chance) {
+            RandomEvent::TaxRevolt
         } else {
-            EventType::TaxRevolt
+            RandomEvent::ArmyDesertion
         };
 
-        match event_type {
-            EventType::ArmyDesertion => Self {
-                event_type,
+        match random_event {
+            RandomEvent::ArmyDesertion => Self {
+                event_type: EventType::ArmyDesertion,
                 title: "Army Unrest".to_string(),
                 description: "Your armies grow restless without pay. The soldiers threaten to desert if their demands are not met.".to_string(),
                 options: vec![
                     EventOption {
                         description: "Pay them 2000 gold to maintain morale".to_string(),
-                        effect: EventEffect::PayGold(2000),
+                        effects: vec![EventEffect::PayGold(2000)],
                     },
                     EventOption {
                         description: "Refuse their demands (desertion of 10% of all units)".to_string(),
-                        effect: EventEffect::LoseArmyUnits(0.1),
+                        effects: vec![EventEffect::LoseArmyUnits(0.1)],
                     },
                 ],
             },
-            EventType::TaxRevolt => Self {
-                event_type,
+            RandomEvent::TaxRevolt => Self {
+                event_type: EventType::TaxRevolt,
                 title: "Tax Revolt".to_string(),
                 description: "The people are angry about high taxes. Protests break out in your provinces.".to_string(),
                 options: vec![
                     EventOption {
-                        description: "Lower taxes and compensate (-1500 gold)".to_string(),
-                        effect: EventEffect::LoseGold(1500),
+                        description: "Lower taxes and compensate (-1500 gold, unrest falls)".to_string(),
+                        effects: vec![EventEffect::LoseGold(1500), EventEffect::ChangeUnrest(-10.0)],
                     },
                     EventOption {
-                        description: "Crack down on protests (lose 5% population)".to_string(),
-                        effect: EventEffect::LosePopulation(0.05),
+                        description: "Crack down on protests (lose 5% population, unrest rises)".to_string(),
+                        effects: vec![EventEffect::LosePopulation(0.05), EventEffect::ChangeUnrest(15.0)],
                     },
                 ],
             },
         }
     }
+
+    pub fn debt_crisis() -> Self {
+        Self {
+            event_type: EventType::DebtCrisis,
+            title: "Creditors at the Gates".to_string(),
+            description: "The treasury could not meet a loan payment. The bankers demand satisfaction and will lend no more until the debt is cleared.".to_string(),
+            options: vec![
+                EventOption {
+                    description: "Leave the soldiers unpaid (desertion of 10% of all units)".to_string(),
+                    effects: vec![EventEffect::LoseArmyUnits(0.1)],
+                },
+                EventOption {
+                    description: "Seize goods from the people (lose 5% population, unrest rises)".to_string(),
+                    effects: vec![EventEffect::LosePopulation(0.05), EventEffect::ChangeUnrest(10.0)],
+                },
+            ],
+        }
+    }
+
+    pub fn call_to_arms(ally: Entity, ally_name: &str, enemy: Entity, enemy_name: &str) -> Self {
+        Self {
+            event_type: EventType::CallToArms,
+            title: "Call to Arms".to_string(),
+            description: format!(
+                "{} has declared war on our ally {}. Their envoy asks us to honour the alliance.",
+                enemy_name, ally_name
+            ),
+            options: vec![
+                EventOption {
+                    description: format!("Honour the alliance and declare war on {}", enemy_name),
+                    effects: vec![EventEffect::JoinWar { ally, enemy }],
+                },
+                EventOption {
+                    description: format!(
+                        "Stay out of the war (the alliance with {} ends)",
+                        ally_name
+                    ),
+                    effects: vec![EventEffect::RefuseCallToArms { ally }],
+                },
+            ],
+        }
+    }
+
+    pub fn claim_discovered(province: Entity, province_id: u32, owner_name: &str) -> Self {
+        Self {
+            event_type: EventType::ClaimDiscovered,
+            title: "Old Charters".to_string(),
+            description: format!(
+                "Scholars have found old charters granting our crown province {}, now held by {}.",
+                province_id, owner_name
+            ),
+            options: vec![
+                EventOption {
+                    description: format!("Press our claim on province {}", province_id),
+                    effects: vec![EventEffect::GainClaim { province }],
+                },
+                EventOption {
+                    description: "Let the matter rest".to_string(),
+                    effects: vec![],
+                },
+            ],
+        }
+    }
 }
 
 #[derive(Resource, Debug, Clone)]
@@ -85,3 +164,9 @@ impl PendingEvent {
         Self { event }
     }
 }
+
+/// Events that came up while another was being shown, in the order they came.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct QueuedEvents {
+    pub events: VecDeque<GameEvent>,
+}
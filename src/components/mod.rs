@@ -1,6 +1,12 @@
 pub mod army;
 pub mod buildings;
 pub mod country;
+pub mod culture;
+pub mod debt;
 pub mod events;
+pub mod game_world_entity;
+pub mod opinion;
+pub mod personality;
 pub mod player;
 pub mod province;
+pub mod trade;
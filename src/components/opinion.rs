@@ -0,0 +1,109 @@
+// components/opinion.rs
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_OPINION: f32 = 200.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum OpinionModifierKind {
+    DeclaredWar,
+    Gift,
+    OccupiesOurLand,
+    SharedEnemy,
+    Borders,
+    Grievances,
+    UnjustifiedWar,
+    AggressiveExpansion,
+}
+
+impl OpinionModifierKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            OpinionModifierKind::DeclaredWar => "Declared war on us",
+            OpinionModifierKind::Gift => "Sent us gifts",
+            OpinionModifierKind::OccupiesOurLand => "Occupies our land",
+            OpinionModifierKind::SharedEnemy => "Shared enemy",
+            OpinionModifierKind::Borders => "Border tension",
+            OpinionModifierKind::Grievances => "Grievances",
+            OpinionModifierKind::UnjustifiedWar => "Started a war without cause",
+            OpinionModifierKind::AggressiveExpansion => "Alarmed by their conquests",
+        }
+    }
+
+    /// Modifiers left by a single deed fade each turn; the others are
+    /// recalculated from the state of the world every turn.
+    pub fn decays(&self) -> bool {
+        matches!(
+            self,
+            OpinionModifierKind::DeclaredWar
+                | OpinionModifierKind::Gift
+                | OpinionModifierKind::UnjustifiedWar
+                | OpinionModifierKind::AggressiveExpansion
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct OpinionModifier {
+    pub kind: OpinionModifierKind,
+    pub value: f32,
+}
+
+/// What this country thinks of every other, as a list of modifiers per country.
+#[derive(Component, Default, Debug, Clone)]
+pub struct Opinions {
+    pub of: HashMap<Entity, Vec<OpinionModifier>>,
+}
+
+impl Opinions {
+    pub fn get(&self, country: Entity) -> f32 {
+        self.modifiers(country)
+            .iter()
+            .map(|modifier| modifier.value)
+            .sum::<f32>()
+            .clamp(-MAX_OPINION, MAX_OPINION)
+    }
+
+    pub fn modifiers(&self, country: Entity) -> &[OpinionModifier] {
+        self.of.get(&country).map_or(&[], Vec::as_slice)
+    }
+
+    /// Adds to the modifier of `kind`, creating it if needed.
+    pub fn add(&mut self, country: Entity, kind: OpinionModifierKind, value: f32) {
+        let modifiers = self.of.entry(country).or_default();
+        match modifiers.iter_mut().find(|modifier| modifier.kind == kind) {
+            Some(modifier) => modifier.value += value,
+            None => modifiers.push(OpinionModifier { kind, value }),
+        }
+    }
+
+    /// Replaces the modifier of `kind`; a value of zero removes it.
+    pub fn set(&mut self, country: Entity, kind: OpinionModifierKind, value: f32) {
+        if value == 0.0 {
+            if let Some(modifiers) = self.of.get_mut(&country) {
+                modifiers.retain(|modifier| modifier.kind != kind);
+            }
+            return;
+        }
+
+        let modifiers = self.of.entry(country).or_default();
+        modifiers.retain(|modifier| modifier.kind != kind);
+        modifiers.push(OpinionModifier { kind, value });
+    }
+
+    /// Moves every decaying modifier `amount` closer to zero.
+    pub fn decay(&mut self, amount: f32) {
+        for modifiers in self.of.values_mut() {
+            for modifier in modifiers.iter_mut().filter(|m| m.kind.decays()) {
+                modifier.value = if modifier.value > 0.0 {
+                    (modifier.value - amount).max(0.0)
+                } else {
+                    (modifier.value + amount).min(0.0)
+                };
+            }
+            modifiers.retain(|modifier| modifier.value != 0.0);
+        }
+        self.of.retain(|_, modifiers| !modifiers.is_empty());
+    }
+}
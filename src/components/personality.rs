@@ -0,0 +1,74 @@
+// components/personality.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How an AI country goes about war, spending and peace.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Personality {
+    #[default]
+    Balanced,
+    /// Quick to go to war and spends on armies.
+    Aggressive,
+    /// Spends on buildings and seldom starts wars.
+    Builder,
+    /// Avoids war and makes peace readily.
+    Diplomat,
+    /// Keeps to itself, arms for defence and holds out once at war.
+    Turtle,
+}
+
+impl Personality {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Personality::Balanced => "Balanced",
+            Personality::Aggressive => "Aggressive",
+            Personality::Builder => "Builder",
+            Personality::Diplomat => "Diplomat",
+            Personality::Turtle => "Turtle",
+        }
+    }
+
+    /// Multiplier on the chance of declaring war.
+    pub fn war_chance(&self) -> f32 {
+        match self {
+            Personality::Balanced => 1.0,
+            Personality::Aggressive => 2.0,
+            Personality::Builder => 0.5,
+            Personality::Diplomat => 0.3,
+            Personality::Turtle => 0.2,
+        }
+    }
+
+    /// Multiplier on the utility of buildings that pay in gold or people.
+    pub fn economy_priority(&self) -> f32 {
+        match self {
+            Personality::Balanced | Personality::Diplomat => 1.0,
+            Personality::Aggressive => 0.6,
+            Personality::Builder => 1.5,
+            Personality::Turtle => 0.8,
+        }
+    }
+
+    /// Multiplier on the utility of recruiting and building barracks.
+    pub fn military_priority(&self) -> f32 {
+        match self {
+            Personality::Balanced => 1.0,
+            Personality::Aggressive => 1.5,
+            Personality::Builder => 0.7,
+            Personality::Diplomat => 0.8,
+            Personality::Turtle => 1.3,
+        }
+    }
+
+    /// War score points the country gives up more readily for peace; below
+    /// zero it holds out for better terms.
+    pub fn peace_willingness(&self) -> i32 {
+        match self {
+            Personality::Balanced => 0,
+            Personality::Aggressive => -5,
+            Personality::Builder => 5,
+            Personality::Diplomat => 10,
+            Personality::Turtle => -10,
+        }
+    }
+}
@@ -1,4 +1,6 @@
 // components/province.rs
+use crate::components::culture::{Culture, Religion};
+use crate::components::trade::TradeGood;
 use bevy::{platform::collections::HashSet, prelude::*};
 use serde::Deserialize;
 use serde::Serialize;
@@ -14,6 +16,10 @@ pub struct Province {
     pub population: u32,
     pub base_growth: f32,
     pub base_income: u32,
+    pub trade_good: Option<TradeGood>,
+    pub culture: Option<Culture>,
+    pub religion: Option<Religion>,
+    pub unrest: f32,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -25,6 +31,12 @@ pub struct ProvinceDef {
     pub population: u32,
     pub base_growth: f32,
     pub base_income: u32,
+    #[serde(default)]
+    pub trade_good: Option<TradeGood>,
+    #[serde(default)]
+    pub culture: Option<Culture>,
+    #[serde(default)]
+    pub religion: Option<Religion>,
 }
 
 #[derive(Component)]
@@ -54,6 +66,18 @@ impl Occupied {
     }
 }
 
+/// The country a province historically belongs to. Ruling it is not foreign rule.
+#[derive(Component)]
+pub struct Core {
+    pub country: Entity,
+}
+
+/// Counts the turns rebels have held an occupied province.
+#[derive(Component, Default)]
+pub struct HeldByRebels {
+    pub turns: u32,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
 pub enum TerrainType {
     Water,
@@ -73,4 +97,15 @@ impl TerrainType {
             TerrainType::Water => Color::srgb(0.3, 0.3, 0.8),
         }
     }
+
+    /// Population the land supports before buildings are taken into account.
+    pub fn carrying_capacity(&self) -> u32 {
+        match self {
+            TerrainType::Plains => 4000,
+            TerrainType::Forest => 2000,
+            TerrainType::Mountains => 1500,
+            TerrainType::City => 8000,
+            TerrainType::Water => 0,
+        }
+    }
 }
@@ -0,0 +1,48 @@
+// components/trade.rs
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum TradeGood {
+    Grain,
+    Wool,
+    Timber,
+    Iron,
+    Cloth,
+}
+
+impl TradeGood {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TradeGood::Grain => "Grain",
+            TradeGood::Wool => "Wool",
+            TradeGood::Timber => "Timber",
+            TradeGood::Iron => "Iron",
+            TradeGood::Cloth => "Cloth",
+        }
+    }
+
+    /// Price used when `map.ron` does not define one for this good.
+    pub fn default_price(&self) -> u32 {
+        match self {
+            TradeGood::Grain => 2,
+            TradeGood::Wool => 3,
+            TradeGood::Timber => 3,
+            TradeGood::Iron => 5,
+            TradeGood::Cloth => 6,
+        }
+    }
+}
+
+pub const ALL_TRADE_GOODS: [TradeGood; 5] = [
+    TradeGood::Grain,
+    TradeGood::Wool,
+    TradeGood::Timber,
+    TradeGood::Iron,
+    TradeGood::Cloth,
+];
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TradeGoodDef {
+    pub good: TradeGood,
+    pub price: u32,
+}
@@ -0,0 +1,172 @@
+// misc/ai_controller.rs
+use crate::components::buildings::{BuildingType, Buildings};
+use crate::components::country::{Relation, Relations};
+use crate::components::culture::Culture;
+use crate::components::opinion::Opinions;
+use crate::components::personality::Personality;
+use crate::components::province::Province;
+use crate::misc::ai_utility::AiWeights;
+use crate::misc::difficulty::Difficulty;
+use crate::misc::economy::province_economy;
+use crate::plugins::claims::CasusBelli;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use std::sync::Arc;
+
+pub const RECRUIT_COST: u32 = 100;
+pub const RECRUIT_UNITS: u32 = 100;
+
+/// An AI that plays a country. Each turn it is shown the game from that
+/// country's side and answers with the orders it wants carried out.
+pub trait AiController: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn decide(&self, view: &CountryView) -> Vec<AiOrder>;
+}
+
+/// The AI playing an `AIControlled` country. Countries without one are played
+/// by `StandardAi`, and so is every country after loading a save.
+#[derive(Component, Clone)]
+pub struct Controller(pub Arc<dyn AiController>);
+
+impl Controller {
+    pub fn new(controller: impl AiController + 'static) -> Self {
+        Controller(Arc::new(controller))
+    }
+}
+
+/// Something an AI wants done. Orders are carried out in the order given;
+/// those the country cannot afford or is not allowed to give are dropped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AiOrder {
+    Build {
+        province: Entity,
+        building: BuildingType,
+    },
+    /// Raises an army of `RECRUIT_UNITS` in a province with barracks.
+    Recruit {
+        province: Entity,
+    },
+    /// Moves an army to a neighbouring province.
+    Move {
+        army: Entity,
+        province: Entity,
+    },
+    DeclareWar {
+        target: Entity,
+    },
+}
+
+pub struct ProvinceView<'a> {
+    pub province: &'a Province,
+    pub buildings: &'a Buildings,
+    pub owner: Entity,
+    pub occupier: Option<Entity>,
+    pub neighbors: Vec<Entity>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ArmyView {
+    pub entity: Entity,
+    pub owner: Entity,
+    pub province: Entity,
+    pub units: u32,
+}
+
+/// Everything every AI country can see, gathered once a turn.
+pub struct WorldView<'a> {
+    pub provinces: HashMap<Entity, ProvinceView<'a>>,
+    pub armies: Vec<ArmyView>,
+    /// Every country but the rebels.
+    pub countries: Vec<Entity>,
+    pub weights: &'a AiWeights,
+    pub difficulty: Difficulty,
+}
+
+impl WorldView<'_> {
+    pub fn units_of(&self, country: Entity) -> u32 {
+        self.armies
+            .iter()
+            .filter(|army| army.owner == country)
+            .map(|army| army.units)
+            .sum()
+    }
+}
+
+/// The game as one country sees it.
+pub struct CountryView<'a> {
+    pub country: Entity,
+    pub gold: u32,
+    pub tax_rate: f32,
+    pub culture: Option<Culture>,
+    pub personality: Personality,
+    pub relations: &'a Relations,
+    pub opinions: &'a Opinions,
+    pub is_vassal: bool,
+    /// Countries whose land this one's armies may march through.
+    pub access_from: HashSet<Entity>,
+    /// Countries this one has a truce with.
+    pub truces: HashSet<Entity>,
+    /// Countries this one has a justification for war against.
+    pub casus_belli: HashMap<Entity, CasusBelli>,
+    pub world: &'a WorldView<'a>,
+}
+
+impl CountryView<'_> {
+    pub fn relation(&self, other: Entity) -> Relation {
+        self.relations.get(other)
+    }
+
+    pub fn at_war(&self) -> bool {
+        self.relations
+            .relations
+            .values()
+            .any(|&r| r == Relation::War)
+    }
+
+    pub fn own_provinces(&self) -> impl Iterator<Item = (Entity, &ProvinceView<'_>)> + '_ {
+        self.world
+            .provinces
+            .iter()
+            .filter(|(_, province)| province.owner == self.country)
+            .map(|(&entity, province)| (entity, province))
+    }
+
+    pub fn own_armies(&self) -> impl Iterator<Item = &ArmyView> + '_ {
+        self.world
+            .armies
+            .iter()
+            .filter(|army| army.owner == self.country)
+    }
+
+    /// What a building costs this country.
+    pub fn building_cost(&self, building: BuildingType) -> u32 {
+        self.world.difficulty.ai_building_cost(building.cost())
+    }
+
+    /// Income and growth a building would add to one of this country's provinces.
+    pub fn building_gain(&self, province: &ProvinceView, building: BuildingType) -> (i64, i32) {
+        let before = province_economy(
+            province.province,
+            province.buildings,
+            self.tax_rate,
+            None,
+            self.culture,
+        );
+
+        let mut with_building = province.buildings.clone();
+        with_building.built.push(building);
+        let after = province_economy(
+            province.province,
+            &with_building,
+            self.tax_rate,
+            None,
+            self.culture,
+        );
+
+        (
+            after.income as i64 - before.income as i64,
+            after.population_change - before.population_change,
+        )
+    }
+}
@@ -0,0 +1,494 @@
+// misc/ai_planner.rs
+use crate::misc::ai_utility::AiWeights;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+/// How a province looks to the country planning its moves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stance {
+    /// Its own land, to be defended.
+    Own,
+    /// Land its armies may march through.
+    Passable,
+    /// Land of a country it is at war with.
+    Enemy,
+    /// Anyone else's land, closed to its armies.
+    Closed,
+}
+
+/// What the planner knows about one province.
+#[derive(Clone, Debug)]
+pub struct ProvinceInfo {
+    pub neighbors: Vec<Entity>,
+    pub stance: Stance,
+    /// Units of countries at war with the planner standing here.
+    pub enemy_units: u32,
+    /// Units of countries at peace with the planner standing here.
+    pub foreign_units: u32,
+}
+
+/// One of the planning country's armies.
+#[derive(Clone, Copy, Debug)]
+pub struct PlannerArmy {
+    pub entity: Entity,
+    pub province: Entity,
+    pub units: u32,
+}
+
+/// Works out where a country's armies should go this turn: attacks they can
+/// win, retreats from fights they cannot, reinforcements for threatened fronts,
+/// and a reserve held back in the interior. Armies without an order hold.
+/// Only the `max_options` weakest attack targets and most threatened fronts
+/// are weighed, so a low number makes for poorer play. Armies up to
+/// `threat_range` provinces away count as a threat, so a higher number sees
+/// attacks coming sooner.
+pub fn plan_army_moves(
+    weights: &AiWeights,
+    provinces: &HashMap<Entity, ProvinceInfo>,
+    armies: &[PlannerArmy],
+    max_options: usize,
+    threat_range: u32,
+) -> Vec<(Entity, Entity)> {
+    let mut garrisons: HashMap<Entity, u32> = HashMap::new();
+    for army in armies {
+        *garrisons.entry(army.province).or_insert(0) += army.units;
+    }
+    let total_units = armies.iter().map(|army| army.units).sum();
+
+    let mut planner = Planner {
+        weights,
+        provinces,
+        max_options,
+        threats: threats(weights, provinces, threat_range),
+        garrisons,
+        free: armies.to_vec(),
+        orders: Vec::new(),
+    };
+    planner.attack();
+    planner.retreat();
+    planner.defend();
+    planner.reinforce(total_units);
+
+    planner.orders
+}
+
+struct Planner<'a> {
+    weights: &'a AiWeights,
+    provinces: &'a HashMap<Entity, ProvinceInfo>,
+    max_options: usize,
+    /// What threatens each province, see `threats`.
+    threats: HashMap<Entity, f32>,
+    /// Own units in each province once the orders so far are carried out.
+    garrisons: HashMap<Entity, u32>,
+    /// Armies that have no order yet.
+    free: Vec<PlannerArmy>,
+    orders: Vec<(Entity, Entity)>,
+}
+
+impl<'a> Planner<'a> {
+    fn neighbors(&self, province: Entity) -> &'a [Entity] {
+        self.provinces
+            .get(&province)
+            .map_or(&[], |info| info.neighbors.as_slice())
+    }
+
+    fn stance(&self, province: Entity) -> Stance {
+        self.provinces
+            .get(&province)
+            .map_or(Stance::Closed, |info| info.stance)
+    }
+
+    fn walkable(&self, province: Entity) -> bool {
+        matches!(self.stance(province), Stance::Own | Stance::Passable)
+    }
+
+    fn garrison(&self, province: Entity) -> u32 {
+        self.garrisons.get(&province).copied().unwrap_or(0)
+    }
+
+    fn threat(&self, province: Entity) -> f32 {
+        self.threats.get(&province).copied().unwrap_or(0.0)
+    }
+
+    /// Enemy units in and next to a province.
+    fn danger(&self, province: Entity) -> u32 {
+        std::iter::once(province)
+            .chain(self.neighbors(province).iter().copied())
+            .filter_map(|p| self.provinces.get(&p))
+            .map(|info| info.enemy_units)
+            .sum()
+    }
+
+    /// Own provinces that border an enemy or have foreign armies nearby.
+    fn is_front(&self, province: Entity) -> bool {
+        self.stance(province) == Stance::Own
+            && (self.threat(province) > 0.0
+                || self
+                    .neighbors(province)
+                    .iter()
+                    .any(|&n| self.stance(n) == Stance::Enemy))
+    }
+
+    /// Units a province still lacks to hold against what threatens it.
+    fn deficit(&self, province: Entity) -> f32 {
+        self.threat(province) * self.weights.desired_superiority - self.garrison(province) as f32
+    }
+
+    /// Whether an army can leave without opening a gap in the front.
+    fn can_spare(&self, army: &PlannerArmy) -> bool {
+        !self.is_front(army.province)
+            || self.garrison(army.province).saturating_sub(army.units) as f32
+                >= self.threat(army.province) * self.weights.desired_superiority
+    }
+
+    fn fronts_in_need(&self) -> Vec<Entity> {
+        self.provinces
+            .keys()
+            .copied()
+            .filter(|&p| self.is_front(p) && self.deficit(p) > 0.0)
+            .collect()
+    }
+
+    fn order(&mut self, army: PlannerArmy, target: Entity) {
+        if let Some(garrison) = self.garrisons.get_mut(&army.province) {
+            *garrison = garrison.saturating_sub(army.units);
+        }
+        *self.garrisons.entry(target).or_insert(0) += army.units;
+        self.free.retain(|free| free.entity != army.entity);
+        self.orders.push((army.entity, target));
+    }
+
+    /// Strikes every enemy province that the armies next to it can take with
+    /// `attack_superiority` to spare, the weakest held first.
+    fn attack(&mut self) {
+        let mut targets: Vec<(Entity, u32)> = self
+            .provinces
+            .iter()
+            .filter(|(_, info)| info.stance == Stance::Enemy)
+            .filter(|(_, info)| {
+                self.free
+                    .iter()
+                    .any(|army| info.neighbors.contains(&army.province))
+            })
+            .map(|(&province, info)| (province, info.enemy_units))
+            .collect();
+        targets.sort_by_key(|&(province, defence)| (defence, province));
+        targets.truncate(self.max_options);
+
+        for (target, defence) in targets {
+            let needed = defence as f32 * self.weights.attack_superiority;
+            let neighbors = self.neighbors(target);
+
+            let mut candidates: Vec<PlannerArmy> = self
+                .free
+                .iter()
+                .copied()
+                .filter(|army| neighbors.contains(&army.province))
+                .collect();
+            candidates.sort_by_key(|army| Reverse(army.units));
+
+            let mut strike = Vec::new();
+            let mut strength = 0;
+            for army in candidates {
+                if strength as f32 > needed {
+                    break;
+                }
+                strength += army.units;
+                strike.push(army);
+            }
+
+            if strength as f32 <= needed {
+                continue;
+            }
+            for army in strike {
+                self.order(army, target);
+            }
+        }
+    }
+
+    /// Pulls armies standing outside their own land back before they are overwhelmed.
+    fn retreat(&mut self) {
+        let exposed: Vec<PlannerArmy> = self
+            .free
+            .iter()
+            .copied()
+            .filter(|army| {
+                self.stance(army.province) != Stance::Own
+                    && self.danger(army.province) > self.garrison(army.province)
+            })
+            .collect();
+
+        for army in exposed {
+            let refuge = self
+                .neighbors(army.province)
+                .iter()
+                .copied()
+                .filter(|&p| self.walkable(p))
+                .min_by(|&a, &b| self.threat(a).total_cmp(&self.threat(b)));
+
+            if let Some(refuge) = refuge {
+                self.order(army, refuge);
+            }
+        }
+    }
+
+    /// Reinforces the most threatened fronts from the provinces next to them.
+    fn defend(&mut self) {
+        let mut fronts = self.fronts_in_need();
+        fronts.sort_by(|&a, &b| self.deficit(b).total_cmp(&self.deficit(a)).then(a.cmp(&b)));
+        fronts.truncate(self.max_options);
+
+        for front in fronts {
+            let neighbors = self.neighbors(front);
+            let mut candidates: Vec<PlannerArmy> = self
+                .free
+                .iter()
+                .copied()
+                .filter(|army| self.walkable(army.province) && neighbors.contains(&army.province))
+                .collect();
+            candidates.sort_by_key(|army| Reverse(army.units));
+
+            for army in candidates {
+                if self.deficit(front) <= 0.0 {
+                    break;
+                }
+                if self.can_spare(&army) {
+                    self.order(army, front);
+                }
+            }
+        }
+    }
+
+    /// Keeps a reserve of `reserve_share` of all units back in the interior and
+    /// marches the other armies there one step closer to the fronts in need.
+    fn reinforce(&mut self, total_units: u32) {
+        let fronts = self.fronts_in_need();
+        if fronts.is_empty() {
+            return;
+        }
+        let distances = self.distances(&fronts);
+
+        let mut interior: Vec<(PlannerArmy, u32)> = self
+            .free
+            .iter()
+            .copied()
+            .filter(|army| !self.is_front(army.province))
+            .filter_map(|army| {
+                distances
+                    .get(&army.province)
+                    .map(|&distance| (army, distance))
+            })
+            .collect();
+        // The reserve is made up of the armies farthest from the fronts.
+        interior.sort_by_key(|&(_, distance)| Reverse(distance));
+
+        let wanted_reserve = total_units as f32 * self.weights.reserve_share;
+        let mut reserve = 0;
+        for (army, distance) in interior {
+            if (reserve as f32) < wanted_reserve {
+                reserve += army.units;
+                continue;
+            }
+
+            let step = self
+                .neighbors(army.province)
+                .iter()
+                .copied()
+                .filter(|p| distances.get(p).is_some_and(|&d| d < distance))
+                .max_by(|&a, &b| self.deficit(a).total_cmp(&self.deficit(b)));
+
+            if let Some(step) = step {
+                self.order(army, step);
+            }
+        }
+    }
+
+    /// Steps from every province the armies can walk through to the nearest goal.
+    fn distances(&self, goals: &[Entity]) -> HashMap<Entity, u32> {
+        let mut distances: HashMap<Entity, u32> = goals.iter().map(|&goal| (goal, 0)).collect();
+        let mut queue: VecDeque<Entity> = goals.iter().copied().collect();
+
+        while let Some(province) = queue.pop_front() {
+            let next = distances.get(&province).copied().unwrap_or(0) + 1;
+            for &neighbor in self.neighbors(province) {
+                if self.walkable(neighbor) && !distances.contains_key(&neighbor) {
+                    distances.insert(neighbor, next);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+/// Armies within `range` provinces of each province, which could attack it
+/// within as many turns. Enemies count in full, peaceful neighbours at
+/// `neighbour_threat`.
+fn threats(
+    weights: &AiWeights,
+    provinces: &HashMap<Entity, ProvinceInfo>,
+    range: u32,
+) -> HashMap<Entity, f32> {
+    provinces
+        .keys()
+        .map(|&province| {
+            let mut near: HashSet<Entity> = HashSet::new();
+            near.insert(province);
+            let mut ring = vec![province];
+            for _ in 0..range {
+                ring = ring
+                    .iter()
+                    .filter_map(|p| provinces.get(p))
+                    .flat_map(|info| info.neighbors.iter().copied())
+                    .filter(|&n| near.insert(n))
+                    .collect();
+            }
+
+            let threat = near
+                .iter()
+                .filter_map(|p| provinces.get(p))
+                .map(|info| {
+                    info.enemy_units as f32 + info.foreign_units as f32 * weights.neighbour_threat
+                })
+                .sum();
+            (province, threat)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::misc::test_entities;
+
+    /// Provinces in a row, each bordering the next.
+    fn line(provinces: &[Entity], stances: &[Stance]) -> HashMap<Entity, ProvinceInfo> {
+        provinces
+            .iter()
+            .enumerate()
+            .map(|(i, &province)| {
+                let neighbors = [i.checked_sub(1), Some(i + 1)]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|j| provinces.get(j).copied())
+                    .collect();
+                let info = ProvinceInfo {
+                    neighbors,
+                    stance: stances[i],
+                    enemy_units: 0,
+                    foreign_units: 0,
+                };
+                (province, info)
+            })
+            .collect()
+    }
+
+    fn army(entity: Entity, province: Entity, units: u32) -> PlannerArmy {
+        PlannerArmy {
+            entity,
+            province,
+            units,
+        }
+    }
+
+    #[test]
+    fn attacks_an_undefended_enemy_province() {
+        let weights = AiWeights::default();
+        let p = test_entities(3);
+        let provinces = line(&p, &[Stance::Own, Stance::Own, Stance::Enemy]);
+
+        let orders = plan_army_moves(
+            &weights,
+            &provinces,
+            &[army(p[0], p[1], 100)],
+            usize::MAX,
+            1,
+        );
+
+        assert_eq!(orders, vec![(p[0], p[2])]);
+    }
+
+    #[test]
+    fn does_not_attack_a_stronger_army() {
+        let weights = AiWeights::default();
+        let p = test_entities(3);
+        let mut provinces = line(&p, &[Stance::Own, Stance::Own, Stance::Enemy]);
+        if let Some(info) = provinces.get_mut(&p[2]) {
+            info.enemy_units = 100;
+        }
+
+        let orders = plan_army_moves(
+            &weights,
+            &provinces,
+            &[army(p[0], p[1], 120)],
+            usize::MAX,
+            1,
+        );
+
+        assert!(orders.iter().all(|&(_, target)| target != p[2]));
+    }
+
+    #[test]
+    fn retreats_from_enemy_land_when_outnumbered() {
+        let weights = AiWeights::default();
+        let p = test_entities(4);
+        let mut provinces = line(
+            &p,
+            &[Stance::Own, Stance::Enemy, Stance::Enemy, Stance::Enemy],
+        );
+        if let Some(info) = provinces.get_mut(&p[2]) {
+            info.enemy_units = 300;
+        }
+
+        let orders = plan_army_moves(
+            &weights,
+            &provinces,
+            &[army(p[0], p[1], 100)],
+            usize::MAX,
+            1,
+        );
+
+        assert_eq!(orders, vec![(p[0], p[0])]);
+    }
+
+    #[test]
+    fn reinforces_a_threatened_front_and_keeps_a_reserve() {
+        let weights = AiWeights::default();
+        let p = test_entities(5);
+        let mut provinces = line(
+            &p,
+            &[
+                Stance::Own,
+                Stance::Own,
+                Stance::Own,
+                Stance::Own,
+                Stance::Enemy,
+            ],
+        );
+        if let Some(info) = provinces.get_mut(&p[4]) {
+            info.enemy_units = 1000;
+        }
+        let far = army(p[0], p[0], 100);
+        let near = army(p[1], p[2], 100);
+
+        let orders = plan_army_moves(&weights, &provinces, &[far, near], usize::MAX, 1);
+
+        assert_eq!(orders, vec![(near.entity, p[3])]);
+    }
+
+    #[test]
+    fn threat_range_reaches_armies_further_away() {
+        let weights = AiWeights::default();
+        let p = test_entities(3);
+        let mut provinces = line(&p, &[Stance::Own, Stance::Own, Stance::Enemy]);
+        if let Some(info) = provinces.get_mut(&p[2]) {
+            info.enemy_units = 50;
+        }
+
+        assert_eq!(threats(&weights, &provinces, 1).get(&p[0]), Some(&0.0));
+        assert_eq!(threats(&weights, &provinces, 2).get(&p[0]), Some(&50.0));
+    }
+}
@@ -0,0 +1,162 @@
+// misc/ai_utility.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How the AI weighs its spending and army options, read from assets/data/ai_weights.ron.
+#[derive(Resource, Clone, Debug, Deserialize, Serialize)]
+pub struct AiWeights {
+    /// Utility of a building that pays for itself at once; it falls off
+    /// linearly to nothing at `max_payback_turns`.
+    pub income: f32,
+    /// Buildings that take longer than this to pay back are not worth their income.
+    pub max_payback_turns: f32,
+    /// Utility per extra person of growth each turn, for every 100 gold spent.
+    pub growth: f32,
+    /// Utility of closing the whole gap between the army the country has and
+    /// the army it needs.
+    pub military: f32,
+    /// Share of a peaceful neighbour's army counted as a threat; enemies count in full.
+    pub neighbour_threat: f32,
+    /// How much stronger than the threat the country wants its army to be.
+    pub desired_superiority: f32,
+    /// Bonus for recruiting in, or building barracks in, a province bordering an enemy.
+    pub frontier: f32,
+    /// Share of the barracks utility a country keeps once it already has barracks.
+    pub extra_barracks: f32,
+    /// Gold kept back in peacetime.
+    pub gold_reserve: u32,
+    /// Options scoring less than this are not worth the gold.
+    pub min_utility: f32,
+    /// How much stronger than the defenders an attack has to be.
+    pub attack_superiority: f32,
+    /// Share of all units held back in the interior rather than sent to a front.
+    pub reserve_share: f32,
+}
+
+impl Default for AiWeights {
+    fn default() -> Self {
+        AiWeights {
+            income: 10.0,
+            max_payback_turns: 40.0,
+            growth: 0.5,
+            military: 12.0,
+            neighbour_threat: 0.25,
+            desired_superiority: 1.2,
+            frontier: 2.0,
+            extra_barracks: 0.2,
+            gold_reserve: 200,
+            min_utility: 1.0,
+            attack_superiority: 1.5,
+            reserve_share: 0.2,
+        }
+    }
+}
+
+/// Utility of spending `cost` gold on `income` more gold and `growth` more people per turn.
+pub fn investment_utility(weights: &AiWeights, cost: u32, income: i64, growth: i32) -> f32 {
+    if cost == 0 {
+        return 0.0;
+    }
+
+    let income_utility = if income > 0 {
+        let payback_turns = cost as f32 / income as f32;
+        weights.income * (1.0 - payback_turns / weights.max_payback_turns).max(0.0)
+    } else {
+        0.0
+    };
+    let growth_utility = weights.growth * growth.max(0) as f32 * 100.0 / cost as f32;
+
+    income_utility + growth_utility
+}
+
+/// How short the country's army falls of what it needs, from 0 (none) to 1
+/// (no army at all), given the units of its neighbours that threaten it.
+pub fn military_need(weights: &AiWeights, own_units: u32, threat: f32) -> f32 {
+    let wanted = threat * weights.desired_superiority;
+    if wanted <= 0.0 {
+        return 0.0;
+    }
+
+    ((wanted - own_units as f32) / wanted).clamp(0.0, 1.0)
+}
+
+/// Utility of recruiting in a province, more so on a front with an enemy.
+pub fn recruit_utility(weights: &AiWeights, need: f32, frontier: bool) -> f32 {
+    let utility = weights.military * need;
+    if frontier {
+        utility + weights.frontier * need
+    } else {
+        utility
+    }
+}
+
+/// Utility of building barracks in a province. Only the first barracks is
+/// needed to recruit at all; more just put recruits closer to the front.
+pub fn barracks_utility(weights: &AiWeights, need: f32, has_barracks: bool, frontier: bool) -> f32 {
+    let utility = recruit_utility(weights, need, frontier);
+    if has_barracks {
+        utility * weights.extra_barracks
+    } else {
+        utility
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn faster_payback_is_worth_more() {
+        let weights = AiWeights::default();
+
+        let quick = investment_utility(&weights, 200, 20, 0);
+        let slow = investment_utility(&weights, 200, 10, 0);
+
+        assert!((quick - 7.5).abs() < 1e-6);
+        assert!((slow - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn income_past_max_payback_is_worthless() {
+        let weights = AiWeights::default();
+
+        assert_eq!(investment_utility(&weights, 200, 2, 0), 0.0);
+        assert_eq!(investment_utility(&weights, 200, -5, 0), 0.0);
+    }
+
+    #[test]
+    fn growth_counts_per_hundred_gold() {
+        let weights = AiWeights::default();
+
+        let utility = investment_utility(&weights, 100, 0, 10);
+
+        assert!((utility - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn no_threat_means_no_military_need() {
+        let weights = AiWeights::default();
+
+        assert_eq!(military_need(&weights, 0, 0.0), 0.0);
+        assert_eq!(military_need(&weights, 500, 100.0), 0.0);
+    }
+
+    #[test]
+    fn military_need_grows_with_the_gap() {
+        let weights = AiWeights::default();
+
+        assert_eq!(military_need(&weights, 0, 500.0), 1.0);
+        assert!((military_need(&weights, 300, 500.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn later_barracks_are_worth_less() {
+        let weights = AiWeights::default();
+
+        let first = barracks_utility(&weights, 1.0, false, false);
+        let extra = barracks_utility(&weights, 1.0, true, true);
+
+        assert_eq!(first, 12.0);
+        assert!((extra - 2.8).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,72 @@
+// misc/difficulty.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// How hard the AI countries play, chosen with the player's country.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+pub const ALL_DIFFICULTIES: [Difficulty; 3] =
+    [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+impl Difficulty {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// Income of an AI country after `income` from its provinces.
+    pub fn ai_income(&self, income: u32) -> u32 {
+        let multiplier = match self {
+            Difficulty::Easy => 0.8,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+        };
+        (income as f32 * multiplier) as u32
+    }
+
+    /// What a building costing `cost` costs an AI country.
+    pub fn ai_building_cost(&self, cost: u32) -> u32 {
+        let multiplier = match self {
+            Difficulty::Easy => 1.2,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.8,
+        };
+        (cost as f32 * multiplier) as u32
+    }
+
+    /// Attack targets and threatened fronts an AI country weighs each turn
+    /// when moving its armies, the most pressing first.
+    pub fn move_options(&self) -> usize {
+        match self {
+            Difficulty::Easy => 2,
+            Difficulty::Normal | Difficulty::Hard => usize::MAX,
+        }
+    }
+
+    /// Provinces away from which an AI country sees enemy armies as a threat.
+    /// On Hard it sees attacks coming a turn earlier.
+    pub fn threat_range(&self) -> u32 {
+        match self {
+            Difficulty::Easy | Difficulty::Normal => 1,
+            Difficulty::Hard => 2,
+        }
+    }
+
+    /// Multiplier on the chance of an AI country declaring war.
+    pub fn aggression(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+        }
+    }
+}
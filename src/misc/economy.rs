@@ -0,0 +1,314 @@
+// misc/economy.rs
+use crate::components::buildings::Buildings;
+use crate::components::country::DEFAULT_TAX_RATE;
+use crate::components::culture::{Culture, CultureMatch};
+use crate::components::province::Province;
+
+pub const OCCUPIED_GROWTH: f32 = -0.05;
+pub const OCCUPIED_INCOME_MULTIPLIER: f32 = 0.5;
+pub const POPULATION_PER_GOLD: u32 = 1000;
+pub const UNITS_PER_UPKEEP_GOLD: u32 = 50;
+/// Share of a vassal's province income paid to its overlord.
+pub const TRIBUTE_SHARE: f32 = 0.25;
+
+/// Income kept from provinces whose culture differs from their owner's.
+pub const CULTURE_GROUP_INCOME_MULTIPLIER: f32 = 0.9;
+pub const FOREIGN_CULTURE_INCOME_MULTIPLIER: f32 = 0.8;
+
+/// Multiplier applied to province income; 1.0 at the default tax rate.
+pub fn tax_income_multiplier(tax_rate: f32) -> f32 {
+    0.5 + tax_rate * 2.5
+}
+
+/// Change to province growth per turn; taxes above the default slow growth down.
+pub fn tax_growth_modifier(tax_rate: f32) -> f32 {
+    (DEFAULT_TAX_RATE - tax_rate) * 0.05
+}
+
+pub fn army_upkeep(units: u32) -> u32 {
+    units / UNITS_PER_UPKEEP_GOLD
+}
+
+pub fn tribute(income: u32) -> u32 {
+    (income as f32 * TRIBUTE_SHARE) as u32
+}
+
+/// Largest population the province's terrain and buildings can support.
+pub fn carrying_capacity(province: &Province, buildings: &Buildings) -> u32 {
+    province.terrain.carrying_capacity()
+        + buildings
+            .built
+            .iter()
+            .map(|b| b.population_bonus())
+            .sum::<u32>()
+}
+
+/// Share of a province's income collected from people of `culture_match`.
+pub fn culture_income_multiplier(culture_match: CultureMatch) -> f32 {
+    match culture_match {
+        CultureMatch::Same => 1.0,
+        CultureMatch::SameGroup => CULTURE_GROUP_INCOME_MULTIPLIER,
+        CultureMatch::Foreign => FOREIGN_CULTURE_INCOME_MULTIPLIER,
+    }
+}
+
+/// Scales positive growth down as the population approaches `capacity`,
+/// and turns it into decline once the population is above it.
+pub fn capacity_factor(population: u32, capacity: u32) -> f32 {
+    if capacity == 0 {
+        return 0.0;
+    }
+
+    (1.0 - population as f32 / capacity as f32).max(-1.0)
+}
+
+/// One turn of growth and income for a province, with every term that went into it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProvinceEconomy {
+    pub base_growth: f32,
+    pub building_growth: f32,
+    pub tax_growth: f32,
+    pub capacity: u32,
+    pub capacity_factor: f32,
+    pub is_occupied: bool,
+    pub growth_rate: f32,
+    pub population_change: i32,
+    pub new_population: u32,
+
+    pub base_income: u32,
+    pub building_income: u32,
+    pub population_income: u32,
+    pub tax_multiplier: f32,
+    pub culture_multiplier: f32,
+    pub occupation_multiplier: f32,
+    pub income: u32,
+}
+
+/// Growth and income of `province` for the coming turn.
+///
+/// The owner's tax rate drives growth. Income goes to whoever controls the province,
+/// so when `occupier_tax_rate` is set the occupier's rate and the occupation penalty apply.
+/// A province whose culture differs from `owner_culture` pays less.
+pub fn province_economy(
+    province: &Province,
+    buildings: &Buildings,
+    owner_tax_rate: f32,
+    occupier_tax_rate: Option<f32>,
+    owner_culture: Option<Culture>,
+) -> ProvinceEconomy {
+    let is_occupied = occupier_tax_rate.is_some();
+
+    let base_growth = province.base_growth;
+    let building_growth: f32 = buildings.built.iter().map(|b| b.growth_bonus()).sum();
+    let tax_growth = tax_growth_modifier(owner_tax_rate);
+
+    let capacity = carrying_capacity(province, buildings);
+    let raw_growth = base_growth + building_growth + tax_growth;
+
+    let capacity_factor = if raw_growth > 0.0 {
+        capacity_factor(province.population, capacity)
+    } else {
+        1.0
+    };
+
+    let growth_rate = if is_occupied {
+        OCCUPIED_GROWTH
+    } else {
+        raw_growth * capacity_factor
+    };
+
+    let growth_amount = (province.population as f32 * growth_rate).round() as i32;
+    let new_population = (province.population as i32 + growth_amount).max(0) as u32;
+
+    let base_income = province.base_income;
+    let building_income: u32 = buildings.built.iter().map(|b| b.income_bonus()).sum();
+    let population_income = new_population / POPULATION_PER_GOLD;
+
+    let tax_multiplier = tax_income_multiplier(occupier_tax_rate.unwrap_or(owner_tax_rate));
+    let culture_multiplier =
+        culture_income_multiplier(CultureMatch::between(province.culture, owner_culture));
+    let occupation_multiplier = if is_occupied {
+        OCCUPIED_INCOME_MULTIPLIER
+    } else {
+        1.0
+    };
+
+    let taxed_income = ((base_income + building_income + population_income) as f32
+        * tax_multiplier
+        * culture_multiplier)
+        .round();
+    let income = (taxed_income * occupation_multiplier) as u32;
+
+    ProvinceEconomy {
+        base_growth,
+        building_growth,
+        tax_growth,
+        capacity,
+        capacity_factor,
+        is_occupied,
+        growth_rate,
+        population_change: new_population as i32 - province.population as i32,
+        new_population,
+        base_income,
+        building_income,
+        population_income,
+        tax_multiplier,
+        culture_multiplier,
+        occupation_multiplier,
+        income,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::buildings::BuildingType;
+    use crate::components::province::TerrainType;
+    use bevy::prelude::*;
+
+    fn province(population: u32, base_growth: f32, base_income: u32) -> Province {
+        Province {
+            id: 1,
+            center: Vec2::ZERO,
+            terrain: TerrainType::Plains,
+            polygon: Vec::new(),
+            neighbors: Default::default(),
+            population,
+            base_growth,
+            base_income,
+            trade_good: None,
+            culture: None,
+            religion: None,
+            unrest: 0.0,
+        }
+    }
+
+    fn buildings(built: &[BuildingType]) -> Buildings {
+        Buildings {
+            built: built.to_vec(),
+        }
+    }
+
+    #[test]
+    fn default_tax_leaves_income_unchanged() {
+        let economy = province_economy(
+            &province(2000, 0.02, 20),
+            &buildings(&[]),
+            DEFAULT_TAX_RATE,
+            None,
+            None,
+        );
+
+        assert_eq!(economy.new_population, 2020);
+        assert_eq!(economy.population_change, 20);
+        assert_eq!(economy.population_income, 2);
+        assert_eq!(economy.income, 22);
+    }
+
+    #[test]
+    fn buildings_add_growth_and_income() {
+        let economy = province_economy(
+            &province(2250, 0.02, 5),
+            &buildings(&[BuildingType::Farm, BuildingType::Mine]),
+            DEFAULT_TAX_RATE,
+            None,
+            None,
+        );
+
+        assert_eq!(economy.capacity, 4500);
+        assert!((economy.growth_rate - 0.015).abs() < 1e-6);
+        assert_eq!(economy.new_population, 2284);
+        assert_eq!(economy.building_income, 10);
+        assert_eq!(economy.income, 17);
+    }
+
+    #[test]
+    fn occupation_shrinks_population_and_halves_income() {
+        let economy = province_economy(
+            &province(1000, 0.01, 20),
+            &buildings(&[BuildingType::Farm]),
+            DEFAULT_TAX_RATE,
+            Some(DEFAULT_TAX_RATE),
+            None,
+        );
+
+        assert_eq!(economy.growth_rate, OCCUPIED_GROWTH);
+        assert_eq!(economy.new_population, 950);
+        assert_eq!(economy.income, 10);
+    }
+
+    #[test]
+    fn occupier_tax_rate_applies_to_occupied_income() {
+        let economy =
+            province_economy(&province(0, 0.0, 20), &buildings(&[]), 0.0, Some(0.5), None);
+
+        assert_eq!(economy.tax_multiplier, 1.75);
+        assert_eq!(economy.income, 17);
+    }
+
+    #[test]
+    fn high_tax_trades_growth_for_income() {
+        let low = province_economy(&province(2000, 0.02, 20), &buildings(&[]), 0.0, None, None);
+        let high = province_economy(&province(2000, 0.02, 20), &buildings(&[]), 0.5, None, None);
+
+        assert_eq!(low.new_population, 2030);
+        assert_eq!(low.income, 11);
+        assert_eq!(high.new_population, 2005);
+        assert_eq!(high.income, 39);
+    }
+
+    #[test]
+    fn growth_slows_near_carrying_capacity() {
+        let economy = province_economy(&province(3900, 0.02, 0), &buildings(&[]), 0.2, None, None);
+
+        assert_eq!(economy.capacity, 4000);
+        assert_eq!(economy.new_population, 3902);
+    }
+
+    #[test]
+    fn population_above_capacity_declines() {
+        let economy = province_economy(&province(5000, 0.02, 0), &buildings(&[]), 0.2, None, None);
+
+        assert!(economy.growth_rate < 0.0);
+        assert_eq!(economy.new_population, 4975);
+    }
+
+    #[test]
+    fn farm_raises_carrying_capacity() {
+        let province = province(0, 0.0, 0);
+
+        assert_eq!(carrying_capacity(&province, &buildings(&[])), 4000);
+        assert_eq!(
+            carrying_capacity(&province, &buildings(&[BuildingType::Farm])),
+            4500
+        );
+    }
+
+    #[test]
+    fn foreign_culture_pays_less() {
+        let mut irish = province(0, 0.0, 20);
+        irish.culture = Some(Culture::Irish);
+
+        let native = province_economy(&irish, &buildings(&[]), 0.2, None, Some(Culture::Irish));
+        let kin = province_economy(&irish, &buildings(&[]), 0.2, None, Some(Culture::Scottish));
+        let foreign = province_economy(&irish, &buildings(&[]), 0.2, None, Some(Culture::English));
+
+        assert_eq!(native.income, 20);
+        assert_eq!(kin.income, 18);
+        assert_eq!(foreign.income, 16);
+    }
+
+    #[test]
+    fn upkeep_is_charged_per_fifty_units() {
+        assert_eq!(army_upkeep(0), 0);
+        assert_eq!(army_upkeep(100), 2);
+        assert_eq!(army_upkeep(149), 2);
+    }
+
+    #[test]
+    fn tribute_is_a_quarter_of_income() {
+        assert_eq!(tribute(0), 0);
+        assert_eq!(tribute(100), 25);
+        assert_eq!(tribute(10), 2);
+    }
+}
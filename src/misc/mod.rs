@@ -1,3 +1,11 @@
+pub mod ai_controller;
+pub mod ai_planner;
+pub mod ai_utility;
+pub mod difficulty;
+pub mod economy;
+pub mod standard_ai;
+pub mod unrest;
+
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy::window::PrimaryWindow;
@@ -56,3 +64,10 @@ pub fn mouse_to_world_coords(
 }
 
 pub fn empty_function() {}
+
+/// Entities for tests of logic that only needs them as ids.
+#[cfg(test)]
+pub fn test_entities(count: usize) -> Vec<Entity> {
+    let mut world = World::new();
+    (0..count).map(|_| world.spawn_empty().id()).collect()
+}
@@ -0,0 +1,258 @@
+// misc/standard_ai.rs
+use crate::components::buildings::{ALL_BUILDINGS, BuildingType};
+use crate::components::country::Relation;
+use crate::misc::ai_controller::{
+    AiController, AiOrder, CountryView, ProvinceView, RECRUIT_COST, RECRUIT_UNITS,
+};
+use crate::misc::ai_planner::{PlannerArmy, ProvinceInfo, Stance, plan_army_moves};
+use crate::misc::ai_utility::{
+    barracks_utility, investment_utility, military_need, recruit_utility,
+};
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+/// Most things an AI country buys in one turn.
+const MAX_PURCHASES_PER_TURN: usize = 2;
+/// Opinion below which an AI country starts considering war.
+const WAR_OPINION: f32 = -10.0;
+
+/// The AI every country plays with unless it is given another. It spends by
+/// utility, moves its armies by front, and picks fights with those it likes least.
+pub struct StandardAi;
+
+impl AiController for StandardAi {
+    fn name(&self) -> &str {
+        "Standard"
+    }
+
+    fn decide(&self, view: &CountryView) -> Vec<AiOrder> {
+        let mut orders = spend_gold(view);
+        orders.extend(move_armies(view));
+        orders.extend(declare_war(view));
+        orders
+    }
+}
+
+fn has_building(
+    planned: &[(Entity, BuildingType)],
+    entity: Entity,
+    province: &ProvinceView,
+    building: BuildingType,
+) -> bool {
+    province.buildings.built.contains(&building) || planned.contains(&(entity, building))
+}
+
+/// Scores every building the country could put up and every place it could
+/// recruit, then buys the best options while they are worth the gold.
+fn spend_gold(view: &CountryView) -> Vec<AiOrder> {
+    let weights = view.world.weights;
+    let mut rng = rand::rng();
+    let mut orders = Vec::new();
+
+    // Shuffle first so that equally good provinces are picked at random.
+    let mut own_provinces: Vec<(Entity, &ProvinceView)> = view.own_provinces().collect();
+    own_provinces.shuffle(&mut rng);
+
+    let mut neighbours: HashSet<Entity> = HashSet::new();
+    let mut frontier: HashSet<Entity> = HashSet::new();
+    for &(entity, province) in &own_provinces {
+        for neighbor in &province.neighbors {
+            let Some(other) = view.world.provinces.get(neighbor).map(|p| p.owner) else {
+                continue;
+            };
+            if other != view.country {
+                neighbours.insert(other);
+                if view.relation(other) == Relation::War {
+                    frontier.insert(entity);
+                }
+            }
+        }
+    }
+
+    let threat: f32 = neighbours
+        .iter()
+        .map(|&neighbour| {
+            let neighbour_units = view.world.units_of(neighbour) as f32;
+            match view.relation(neighbour) {
+                Relation::War => neighbour_units,
+                Relation::Peace => neighbour_units * weights.neighbour_threat,
+                Relation::Alliance => 0.0,
+            }
+        })
+        .sum();
+
+    let reserve = if view.at_war() {
+        0
+    } else {
+        weights.gold_reserve
+    };
+    let mut gold = view.gold;
+    let mut units = view.world.units_of(view.country);
+    // Buildings ordered this turn, which the view does not show yet.
+    let mut planned: Vec<(Entity, BuildingType)> = Vec::new();
+
+    for _ in 0..MAX_PURCHASES_PER_TURN {
+        let budget = gold.saturating_sub(reserve);
+        let need = military_need(weights, units, threat);
+        let has_barracks = own_provinces.iter().any(|&(entity, province)| {
+            has_building(&planned, entity, province, BuildingType::Barracks)
+        });
+
+        let mut best: Option<(f32, AiOrder, u32)> = None;
+        let mut consider = |utility: f32, order: AiOrder, cost: u32| {
+            if best.is_none_or(|(best_utility, ..)| utility > best_utility) {
+                best = Some((utility, order, cost));
+            }
+        };
+
+        for &(entity, province) in &own_provinces {
+            let on_frontier = frontier.contains(&entity);
+
+            for building in ALL_BUILDINGS {
+                let cost = view.building_cost(building);
+                if has_building(&planned, entity, province, building) || cost > budget {
+                    continue;
+                }
+
+                let (income, growth) = view.building_gain(province, building);
+                let mut utility = investment_utility(weights, cost, income, growth)
+                    * view.personality.economy_priority();
+                if building == BuildingType::Barracks {
+                    utility += barracks_utility(weights, need, has_barracks, on_frontier)
+                        * view.personality.military_priority();
+                }
+                let order = AiOrder::Build {
+                    province: entity,
+                    building,
+                };
+                consider(utility, order, cost);
+            }
+
+            if has_building(&planned, entity, province, BuildingType::Barracks)
+                && RECRUIT_COST <= budget
+            {
+                consider(
+                    recruit_utility(weights, need, on_frontier)
+                        * view.personality.military_priority(),
+                    AiOrder::Recruit { province: entity },
+                    RECRUIT_COST,
+                );
+            }
+        }
+
+        let Some((utility, order, cost)) = best else {
+            break;
+        };
+        if utility < weights.min_utility {
+            break;
+        }
+
+        gold -= cost;
+        match order {
+            AiOrder::Build { province, building } => planned.push((province, building)),
+            AiOrder::Recruit { .. } => units += RECRUIT_UNITS,
+            _ => {}
+        }
+        orders.push(order);
+    }
+
+    orders
+}
+
+/// Plans army moves from the country's fronts, see misc/ai_planner.rs.
+fn move_armies(view: &CountryView) -> Vec<AiOrder> {
+    let mut map: HashMap<Entity, ProvinceInfo> = view
+        .world
+        .provinces
+        .iter()
+        .map(|(&entity, province)| {
+            let owner = province.owner;
+            let stance = if owner == view.country {
+                Stance::Own
+            } else if view.relation(owner) == Relation::War {
+                Stance::Enemy
+            } else if view.access_from.contains(&owner) {
+                Stance::Passable
+            } else {
+                Stance::Closed
+            };
+            let info = ProvinceInfo {
+                neighbors: province.neighbors.clone(),
+                stance,
+                enemy_units: 0,
+                foreign_units: 0,
+            };
+            (entity, info)
+        })
+        .collect();
+
+    let mut own_armies = Vec::new();
+    for army in &view.world.armies {
+        if army.owner == view.country {
+            own_armies.push(PlannerArmy {
+                entity: army.entity,
+                province: army.province,
+                units: army.units,
+            });
+            continue;
+        }
+
+        let Some(info) = map.get_mut(&army.province) else {
+            continue;
+        };
+        match view.relation(army.owner) {
+            Relation::War => info.enemy_units += army.units,
+            Relation::Peace => info.foreign_units += army.units,
+            Relation::Alliance => {}
+        }
+    }
+
+    plan_army_moves(
+        view.world.weights,
+        &map,
+        &own_armies,
+        view.world.difficulty.move_options(),
+        view.world.difficulty.threat_range(),
+    )
+    .into_iter()
+    .map(|(army, province)| AiOrder::Move { army, province })
+    .collect()
+}
+
+/// Weighs war against the country this one likes least. Peace is negotiated
+/// separately, see plugins/peace.rs.
+fn declare_war(view: &CountryView) -> Option<AiOrder> {
+    if view.is_vassal {
+        return None;
+    }
+
+    let target = view
+        .world
+        .countries
+        .iter()
+        .copied()
+        .filter(|&e| e != view.country && !view.truces.contains(&e))
+        .filter(|&e| view.relation(e) == Relation::Peace)
+        .min_by(|&a, &b| view.opinions.get(a).total_cmp(&view.opinions.get(b)))?;
+
+    // The more a country is disliked, the likelier the war. A casus belli
+    // makes war tempting sooner, and without one the AI thinks twice.
+    let opinion = view.opinions.get(target);
+    let casus_belli = view.casus_belli.contains_key(&target);
+    let threshold = if casus_belli { 0.0 } else { WAR_OPINION };
+    if opinion > threshold {
+        return None;
+    }
+    let mut war_chance = (0.05 + (threshold - opinion) / 100.0).min(0.5);
+    if !casus_belli {
+        war_chance /= 2.0;
+    }
+    war_chance *= view.personality.war_chance() * view.world.difficulty.aggression();
+    war_chance = war_chance.min(1.0);
+
+    rand::rng()
+        .random_bool(war_chance as f64)
+        .then_some(AiOrder::DeclareWar { target })
+}
@@ -0,0 +1,77 @@
+// misc/unrest.rs
+use crate::components::country::DEFAULT_TAX_RATE;
+use crate::components::culture::CultureMatch;
+
+pub const MAX_UNREST: f32 = 100.0;
+pub const REBELLION_THRESHOLD: f32 = 50.0;
+/// Unrest left in a province once its rebels have taken up arms.
+pub const UNREST_AFTER_UPRISING: f32 = 25.0;
+/// Turns rebels must hold a province before it breaks away.
+pub const REBEL_VICTORY_TURNS: u32 = 5;
+
+const OCCUPATION_UNREST: f32 = 6.0;
+const FOREIGN_RULE_UNREST: f32 = 3.0;
+const TAX_UNREST: f32 = 20.0;
+const FOREIGN_CULTURE_UNREST: f32 = 1.5;
+const CULTURE_GROUP_UNREST: f32 = 0.5;
+const RELIGION_UNREST: f32 = 2.0;
+const UNREST_DECAY: f32 = 2.0;
+
+/// Change of a province's unrest over one turn, with every term that went into it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UnrestChange {
+    pub occupation: f32,
+    pub foreign_rule: f32,
+    pub taxes: f32,
+    pub culture: f32,
+    pub religion: f32,
+    pub decay: f32,
+    pub total: f32,
+}
+
+pub fn unrest_change(
+    is_occupied: bool,
+    is_foreign: bool,
+    owner_tax_rate: f32,
+    culture_match: CultureMatch,
+    religion_differs: bool,
+) -> UnrestChange {
+    let occupation = if is_occupied { OCCUPATION_UNREST } else { 0.0 };
+    let foreign_rule = if is_foreign { FOREIGN_RULE_UNREST } else { 0.0 };
+    let taxes = (owner_tax_rate - DEFAULT_TAX_RATE) * TAX_UNREST;
+    let culture = match culture_match {
+        CultureMatch::Same => 0.0,
+        CultureMatch::SameGroup => CULTURE_GROUP_UNREST,
+        CultureMatch::Foreign => FOREIGN_CULTURE_UNREST,
+    };
+    let religion = if religion_differs {
+        RELIGION_UNREST
+    } else {
+        0.0
+    };
+    let decay = -UNREST_DECAY;
+
+    UnrestChange {
+        occupation,
+        foreign_rule,
+        taxes,
+        culture,
+        religion,
+        decay,
+        total: occupation + foreign_rule + taxes + culture + religion + decay,
+    }
+}
+
+pub fn apply_unrest_change(unrest: f32, change: f32) -> f32 {
+    (unrest + change).clamp(0.0, MAX_UNREST)
+}
+
+/// Chance per turn that a province above the rebellion threshold rises up.
+pub fn uprising_chance(unrest: f32) -> f64 {
+    let over = (unrest - REBELLION_THRESHOLD) / (MAX_UNREST - REBELLION_THRESHOLD);
+    (over.clamp(0.0, 1.0) * 0.5) as f64
+}
+
+pub fn rebel_units(population: u32) -> u32 {
+    (population / 10).clamp(50, 1000)
+}
@@ -0,0 +1,287 @@
+// plugins/alliances.rs
+use crate::components::country::{
+    AIControlled, Country, DiplomacyChanged, Grievances, Relation, Relations, VassalOf,
+};
+use crate::components::events::{GameEvent, PendingEvent, QueuedEvents};
+use crate::components::player::{ControlsCountry, LocalPlayer};
+use crate::plugins::proposals::{ProposalAnswered, ProposalKind, Proposals};
+use crate::plugins::truces::Truces;
+use crate::plugins::turn::TurnResolutionSet;
+use crate::states::GamePhase;
+use bevy::prelude::*;
+use rand::Rng;
+use rand::prelude::IndexedRandom;
+
+/// Grievance an ally holds against a country that ignored its call to arms.
+pub const REFUSED_CALL_GRIEVANCE: f32 = 20.0;
+/// Grievance a country holds against an ally that walked out of the alliance.
+pub const LEFT_ALLIANCE_GRIEVANCE: f32 = 5.0;
+
+pub struct AlliancesPlugin;
+
+impl Plugin for AlliancesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (ai_answer_alliance_proposals, ai_form_alliances)
+                .chain()
+                .in_set(TurnResolutionSet::AIDecision),
+        )
+        .add_observer(on_war_call_allies)
+        .add_observer(on_alliance_proposal_answered);
+    }
+}
+
+/// Whether a country with `relations` and `grievances` agrees to ally with `proposer`.
+/// Countries never ally with someone they resent, and gladly do against a shared enemy.
+pub fn accepts_alliance(
+    relations: &Relations,
+    grievances: &Grievances,
+    proposer: Entity,
+    proposer_relations: &Relations,
+) -> bool {
+    if relations.get(proposer) != Relation::Peace || grievances.get(proposer) > 0.0 {
+        return false;
+    }
+
+    let shared_enemy = relations.relations.iter().any(|(&other, &relation)| {
+        relation == Relation::War && proposer_relations.get(other) == Relation::War
+    });
+
+    shared_enemy || rand::rng().random_bool(0.3)
+}
+
+/// Forms the alliance once the receiver of a proposal accepts it.
+fn on_alliance_proposal_answered(
+    trigger: On<ProposalAnswered>,
+    mut commands: Commands,
+    mut relations: Query<&mut Relations>,
+) {
+    let ev = trigger.event();
+    let proposal = &ev.proposal;
+
+    if !ev.accepted || !matches!(proposal.kind, ProposalKind::Alliance) {
+        return;
+    }
+
+    let Ok([mut from_relations, mut to_relations]) =
+        relations.get_many_mut([proposal.from, proposal.to])
+    else {
+        return;
+    };
+
+    if from_relations.get(proposal.to) != Relation::Peace {
+        return;
+    }
+
+    from_relations.set(proposal.to, Relation::Alliance);
+    to_relations.set(proposal.from, Relation::Alliance);
+
+    commands.trigger(DiplomacyChanged {
+        declarer: proposal.from,
+        target: proposal.to,
+        new_relation: Relation::Alliance,
+    });
+}
+
+fn ai_answer_alliance_proposals(
+    mut commands: Commands,
+    mut proposals: ResMut<Proposals>,
+    ai_countries: Query<(&Relations, &Grievances, Has<VassalOf>), With<AIControlled>>,
+    relations: Query<&Relations>,
+) {
+    let offers = proposals.take(|proposal| {
+        matches!(proposal.kind, ProposalKind::Alliance) && ai_countries.contains(proposal.to)
+    });
+
+    for proposal in offers {
+        // Vassals leave their alliances to their overlord.
+        let accepted = match (ai_countries.get(proposal.to), relations.get(proposal.from)) {
+            (Ok((to_relations, grievances, false)), Ok(from_relations)) => {
+                accepts_alliance(to_relations, grievances, proposal.from, from_relations)
+            }
+            _ => false,
+        };
+        commands.trigger(ProposalAnswered { proposal, accepted });
+    }
+}
+
+type AllianceCountryQuery<'a> = (
+    &'a Country,
+    &'a mut Relations,
+    &'a mut Grievances,
+    Has<AIControlled>,
+    Option<&'a VassalOf>,
+);
+
+fn on_war_call_allies(
+    trigger: On<DiplomacyChanged>,
+    mut commands: Commands,
+    mut countries: Query<AllianceCountryQuery>,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+    (pending_event, mut queued_events, truces): (
+        Option<Res<PendingEvent>>,
+        ResMut<QueuedEvents>,
+        Res<Truces>,
+    ),
+    mut next_state: ResMut<NextState<GamePhase>>,
+) {
+    let ev = trigger.event();
+
+    if ev.new_relation != Relation::War {
+        return;
+    }
+
+    let attacker = ev.declarer;
+    let defender = ev.target;
+
+    let Ok((defender_country, defender_relations, _, _, defender_vassal_of)) =
+        countries.get(defender)
+    else {
+        return;
+    };
+    let defender_name = defender_country.name.clone();
+    let defender_overlord = defender_vassal_of.map(|v| v.overlord);
+    let allies: Vec<Entity> = defender_relations.allies().collect();
+
+    let Ok((attacker_country, ..)) = countries.get(attacker) else {
+        return;
+    };
+    let attacker_name = attacker_country.name.clone();
+
+    let player_country = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0);
+
+    let mut rng = rand::rng();
+
+    for ally in allies {
+        let Ok((ally_country, mut ally_relations, ally_grievances, is_ai, ally_vassal_of)) =
+            countries.get_mut(ally)
+        else {
+            continue;
+        };
+
+        // Vassals and overlords are bound to each other's wars, see plugins/vassals.rs.
+        if ally_vassal_of.is_some_and(|v| v.overlord == defender) || defender_overlord == Some(ally)
+        {
+            continue;
+        }
+
+        // An ally of both sides, or one bound by a truce, stays out without breaking the alliance.
+        if ally == attacker
+            || ally_relations.get(attacker) != Relation::Peace
+            || truces.between(ally, attacker)
+        {
+            continue;
+        }
+
+        if !is_ai {
+            if Some(ally) == player_country {
+                let call =
+                    GameEvent::call_to_arms(defender, &defender_name, attacker, &attacker_name);
+                // The player answers every call, even one that comes while another event waits.
+                if pending_event.is_none() {
+                    commands.insert_resource(PendingEvent::new(call));
+                    next_state.set(GamePhase::Event);
+                } else {
+                    queued_events.events.push_back(call);
+                }
+            }
+            continue;
+        }
+
+        let joins = ally_grievances.get(defender) <= 0.0 && rng.random_bool(0.75);
+
+        if joins {
+            ally_relations.set(attacker, Relation::War);
+            println!(
+                "{} answered the call to arms of {} against {}",
+                ally_country.name, defender_name, attacker_name
+            );
+
+            if let Ok((_, mut attacker_relations, ..)) = countries.get_mut(attacker) {
+                attacker_relations.set(ally, Relation::War);
+            }
+        } else {
+            ally_relations.set(defender, Relation::Peace);
+            println!(
+                "{} refused the call to arms of {}, the alliance is over",
+                ally_country.name, defender_name
+            );
+
+            if let Ok((_, mut defender_relations, mut defender_grievances, ..)) =
+                countries.get_mut(defender)
+            {
+                defender_relations.set(ally, Relation::Peace);
+                defender_grievances.add(ally, REFUSED_CALL_GRIEVANCE);
+            }
+        }
+    }
+}
+
+fn ai_form_alliances(
+    mut commands: Commands,
+    mut countries: Query<
+        (Entity, &Country, &mut Relations, &Grievances),
+        (With<AIControlled>, Without<VassalOf>),
+    >,
+) {
+    let mut rng = rand::rng();
+    let candidates: Vec<Entity> = countries.iter().map(|(entity, ..)| entity).collect();
+
+    for &country_entity in &candidates {
+        if !rng.random_bool(0.1) {
+            continue;
+        }
+
+        let Some(&partner) = candidates.choose(&mut rng) else {
+            continue;
+        };
+
+        if partner == country_entity {
+            continue;
+        }
+
+        let Ok(
+            [
+                (_, country, relations, grievances),
+                (_, partner_country, partner_relations, partner_grievances),
+            ],
+        ) = countries.get_many([country_entity, partner])
+        else {
+            continue;
+        };
+
+        let agreed = accepts_alliance(relations, grievances, partner, partner_relations)
+            && accepts_alliance(
+                partner_relations,
+                partner_grievances,
+                country_entity,
+                relations,
+            );
+
+        if !agreed {
+            continue;
+        }
+
+        println!(
+            "{} and {} formed an alliance",
+            country.name, partner_country.name
+        );
+
+        if let Ok([(_, _, mut relations, _), (_, _, mut partner_relations, _)]) =
+            countries.get_many_mut([country_entity, partner])
+        {
+            relations.set(partner, Relation::Alliance);
+            partner_relations.set(country_entity, Relation::Alliance);
+
+            commands.trigger(DiplomacyChanged {
+                declarer: country_entity,
+                target: partner,
+                new_relation: Relation::Alliance,
+            });
+        }
+    }
+}
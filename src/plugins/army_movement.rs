@@ -1,7 +1,8 @@
 use crate::components::army::{Army, PendingMove};
-use crate::components::country::{Relation, Relations};
+use crate::components::country::Relations;
 use crate::components::province::{OwnedBy, Province, TerrainType};
 use crate::misc::{MouseAndWindowAndCamera, mouse_to_world_coords, squared_distance};
+use crate::plugins::military_access::{MilitaryAccess, may_enter};
 use crate::plugins::selection::{CurrentSelection, SelectedEntity};
 use crate::states::{AppState, GamePhase};
 use bevy::ecs::system::SystemParam;
@@ -20,10 +21,11 @@ impl Plugin for ArmyMovementPlugin {
     }
 }
 
-#[derive(SystemParam, Copy, Clone, Debug)]
+#[derive(SystemParam, Debug)]
 struct ArmyMoveQueries<'w, 's> {
     armies: Query<'w, 's, &'static Army>,
     pending_moves: Query<'w, 's, &'static PendingMove>,
+    access: Res<'w, MilitaryAccess>,
 }
 
 fn queue_army_move(
@@ -41,6 +43,7 @@ fn queue_army_move(
 
     let armies = army_move_queries.armies;
     let pending_moves = army_move_queries.pending_moves;
+    let access = army_move_queries.access;
     let mouse_buttons = mouse_and_window_and_cam.mouse;
     let window_query = mouse_and_window_and_cam.window;
     let camera_query = mouse_and_window_and_cam.camera;
@@ -78,14 +81,10 @@ fn queue_army_move(
     let is_adjacent = current_prov.neighbors.contains(&target_province.id);
     let is_land = target_province.terrain != TerrainType::Water;
 
-    let can_enter = if army.owner == target_owned_by.owner {
-        true
-    } else {
-        relations
+    let can_enter = army.owner == target_owned_by.owner
+        || relations
             .get(army.owner)
-            .ok()
-            .is_some_and(|rels| rels.get(target_owned_by.owner) == Relation::War)
-    };
+            .is_ok_and(|rels| may_enter(rels, &access, army.owner, target_owned_by.owner));
 
     let is_valid_target = is_adjacent && is_land && can_enter;
 
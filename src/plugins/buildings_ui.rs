@@ -4,6 +4,7 @@ use crate::components::buildings::{ALL_BUILDINGS, BuildingType, Buildings};
 use crate::components::country::*;
 use crate::components::player::{ControlsCountry, LocalPlayer};
 use crate::components::province::*;
+use crate::plugins::ledger::{BudgetLedger, ExpenseKind};
 use crate::plugins::selection::CurrentSelection;
 use crate::plugins::selection::SelectedEntity;
 use crate::states::AppState;
@@ -30,6 +31,7 @@ fn province_building_ui(
     mut countries: Query<&mut Country>,
     local_player: Option<Res<LocalPlayer>>,
     player_query: Query<&ControlsCountry>,
+    mut ledger: ResMut<BudgetLedger>,
 ) {
     let Ok(ctx) = contexts.ctx_mut() else {
         return;
@@ -77,6 +79,7 @@ fn province_building_ui(
                         if ui.button("Build").clicked() && can_afford {
                             player_country.gold -= cost;
                             buildings.built.push(building_type);
+                            ledger.record_expense(owned_by.owner, ExpenseKind::Building, cost);
                         }
                     });
                 }
@@ -88,6 +91,7 @@ fn province_building_ui(
                         && player_country.gold >= 100
                     {
                         player_country.gold -= 100;
+                        ledger.record_expense(owned_by.owner, ExpenseKind::Recruiting, 100);
 
                         let _army_entity = commands
                             .spawn((
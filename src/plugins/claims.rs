@@ -0,0 +1,288 @@
+// plugins/claims.rs
+use crate::components::country::{AIControlled, Country, RebelFaction, Relation, Relations};
+use crate::components::opinion::{OpinionModifierKind, Opinions};
+use crate::components::province::{Core, OwnedBy, Province};
+use crate::misc::unrest::apply_unrest_change;
+use crate::plugins::turn::TurnResolutionSet;
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use rand::Rng;
+use rand::prelude::IndexedRandom;
+
+/// Turns it takes to fabricate a claim on a province.
+pub const FABRICATION_TURNS: u32 = 5;
+/// Opinion every other country loses of one that declares war without a casus belli.
+const NO_CASUS_BELLI_OPINION: f32 = -25.0;
+/// Unrest in the declarer's own provinces after a war without a casus belli.
+const NO_CASUS_BELLI_UNREST: f32 = 10.0;
+
+pub struct ClaimsPlugin;
+
+impl Plugin for ClaimsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Claims>()
+            .add_systems(
+                Update,
+                ai_fabricate_claims.in_set(TurnResolutionSet::AIDecision),
+            )
+            .add_systems(Update, update_claims.in_set(TurnResolutionSet::End))
+            .add_observer(on_unjustified_war)
+            .add_observer(on_fabricate_claim);
+    }
+}
+
+/// A claim being forged on `province` for `claimant`.
+#[derive(Clone, Debug)]
+pub struct Fabrication {
+    pub claimant: Entity,
+    pub province: Entity,
+    pub turns_left: u32,
+}
+
+/// Claims as (claimant, province) pairs, and the claims still being fabricated.
+/// A country also claims every province that is its core.
+#[derive(Resource, Default)]
+pub struct Claims {
+    pub claims: HashSet<(Entity, Entity)>,
+    pub fabrications: Vec<Fabrication>,
+}
+
+impl Claims {
+    pub fn has(&self, claimant: Entity, province: Entity) -> bool {
+        self.claims.contains(&(claimant, province))
+    }
+
+    pub fn add(&mut self, claimant: Entity, province: Entity) {
+        self.claims.insert((claimant, province));
+    }
+
+    /// The claim `claimant` is fabricating, if any. A country forges one claim at a time.
+    pub fn fabricating(&self, claimant: Entity) -> Option<&Fabrication> {
+        self.fabrications
+            .iter()
+            .find(|fabrication| fabrication.claimant == claimant)
+    }
+
+    pub fn start_fabrication(&mut self, claimant: Entity, province: Entity) {
+        if self.fabricating(claimant).is_some() {
+            return;
+        }
+        self.fabrications.push(Fabrication {
+            claimant,
+            province,
+            turns_left: FABRICATION_TURNS,
+        });
+    }
+}
+
+/// Justification for a war, strongest first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CasusBelli {
+    /// The target holds some of the declarer's cores.
+    Reconquest,
+    /// The declarer has claims on the target's land.
+    Claim,
+}
+
+impl CasusBelli {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CasusBelli::Reconquest => "Reconquest",
+            CasusBelli::Claim => "Claim",
+        }
+    }
+}
+
+/// Whether `claimant` claims a province with `core`, either outright or as its core.
+pub fn claims_province(
+    claims: &Claims,
+    claimant: Entity,
+    province: Entity,
+    core: Option<&Core>,
+) -> bool {
+    claims.has(claimant, province) || core.is_some_and(|core| core.country == claimant)
+}
+
+/// Claims and land, to tell which wars are justified.
+#[derive(SystemParam)]
+pub struct WarGoals<'w, 's> {
+    claims: Res<'w, Claims>,
+    provinces: Query<'w, 's, (Entity, &'static OwnedBy, Option<&'static Core>)>,
+}
+
+impl WarGoals<'_, '_> {
+    pub fn claims(&self) -> &Claims {
+        &self.claims
+    }
+
+    pub fn claims_province(&self, claimant: Entity, province: Entity) -> bool {
+        let core = self
+            .provinces
+            .get(province)
+            .ok()
+            .and_then(|(_, _, core)| core);
+        claims_province(&self.claims, claimant, province, core)
+    }
+
+    pub fn casus_belli(&self, declarer: Entity, target: Entity) -> Option<CasusBelli> {
+        let mut best = None;
+
+        for (province, owned_by, core) in &self.provinces {
+            if owned_by.owner != target {
+                continue;
+            }
+            if core.is_some_and(|core| core.country == declarer) {
+                return Some(CasusBelli::Reconquest);
+            }
+            if self.claims.has(declarer, province) {
+                best = Some(CasusBelli::Claim);
+            }
+        }
+
+        best
+    }
+}
+
+/// Asks for a claim on `province` to be fabricated for `claimant`.
+#[derive(Event)]
+pub struct FabricateClaim {
+    pub claimant: Entity,
+    pub province: Entity,
+}
+
+fn on_fabricate_claim(trigger: On<FabricateClaim>, mut claims: ResMut<Claims>) {
+    let ev = trigger.event();
+    claims.start_fabrication(ev.claimant, ev.province);
+}
+
+/// A war declared without a casus belli.
+#[derive(Event)]
+pub struct UnjustifiedWar {
+    pub declarer: Entity,
+    pub target: Entity,
+}
+
+/// Everyone thinks less of a country that starts a war for no reason, and its
+/// own people resent being dragged into one.
+fn on_unjustified_war(
+    trigger: On<UnjustifiedWar>,
+    mut opinions: Query<(Entity, &mut Opinions), Without<RebelFaction>>,
+    mut provinces: Query<(&mut Province, &OwnedBy)>,
+    countries: Query<&Country>,
+) {
+    let ev = trigger.event();
+
+    for (country, mut country_opinions) in &mut opinions {
+        if country != ev.declarer {
+            country_opinions.add(
+                ev.declarer,
+                OpinionModifierKind::UnjustifiedWar,
+                NO_CASUS_BELLI_OPINION,
+            );
+        }
+    }
+
+    for (mut province, owned_by) in &mut provinces {
+        if owned_by.owner == ev.declarer {
+            province.unrest = apply_unrest_change(province.unrest, NO_CASUS_BELLI_UNREST);
+        }
+    }
+
+    if let (Ok(declarer), Ok(target)) = (countries.get(ev.declarer), countries.get(ev.target)) {
+        println!(
+            "{} declared war on {} without a casus belli",
+            declarer.name, target.name
+        );
+    }
+}
+
+/// Finishes fabrications and drops claims on land the claimant now owns.
+fn update_claims(
+    mut claims: ResMut<Claims>,
+    provinces: Query<(&Province, &OwnedBy)>,
+    countries: Query<&Country>,
+) {
+    let owns = |claimant: Entity, province: Entity| {
+        provinces
+            .get(province)
+            .is_ok_and(|(_, owned_by)| owned_by.owner == claimant)
+    };
+
+    let mut finished = Vec::new();
+    for fabrication in &mut claims.fabrications {
+        fabrication.turns_left = fabrication.turns_left.saturating_sub(1);
+        if fabrication.turns_left == 0 {
+            finished.push((fabrication.claimant, fabrication.province));
+        }
+    }
+    claims
+        .fabrications
+        .retain(|fabrication| fabrication.turns_left > 0);
+
+    for (claimant, province) in finished {
+        claims.add(claimant, province);
+        if let (Ok(country), Ok((province, _))) = (countries.get(claimant), provinces.get(province))
+        {
+            println!(
+                "{} finished fabricating a claim on province {}",
+                country.name, province.id
+            );
+        }
+    }
+
+    claims
+        .claims
+        .retain(|&(claimant, province)| !owns(claimant, province));
+}
+
+/// AI countries forge claims on the land of the neighbour they like least.
+fn ai_fabricate_claims(
+    mut claims: ResMut<Claims>,
+    ai_countries: Query<(Entity, &Country, &Relations, &Opinions), With<AIControlled>>,
+    countries: Query<Entity, (With<Country>, Without<RebelFaction>)>,
+    provinces: Query<(Entity, &Province, &OwnedBy)>,
+) {
+    let mut rng = rand::rng();
+
+    let owners: HashMap<u32, Entity> = provinces
+        .iter()
+        .map(|(_, province, owned_by)| (province.id, owned_by.owner))
+        .collect();
+
+    for (country_entity, country, relations, opinions) in &ai_countries {
+        if claims.fabricating(country_entity).is_some() || !rng.random_bool(0.1) {
+            continue;
+        }
+
+        let neighbours: HashSet<Entity> = provinces
+            .iter()
+            .filter(|(_, _, owned_by)| owned_by.owner == country_entity)
+            .flat_map(|(_, province, _)| province.neighbors.iter())
+            .filter_map(|id| owners.get(id).copied())
+            .filter(|&owner| owner != country_entity && countries.contains(owner))
+            .collect();
+
+        let rival = neighbours
+            .into_iter()
+            .filter(|&other| relations.get(other) == Relation::Peace)
+            .min_by(|&a, &b| opinions.get(a).total_cmp(&opinions.get(b)));
+
+        let Some(rival) = rival else {
+            continue;
+        };
+
+        let targets: Vec<Entity> = provinces
+            .iter()
+            .filter(|&(province, _, owned_by)| {
+                owned_by.owner == rival && !claims.has(country_entity, province)
+            })
+            .map(|(province, ..)| province)
+            .collect();
+
+        if let Some(&province) = targets.choose(&mut rng) {
+            claims.start_fabrication(country_entity, province);
+            println!("{} started fabricating a claim", country.name);
+        }
+    }
+}
@@ -0,0 +1,345 @@
+// plugins/coalitions.rs
+use crate::components::country::{
+    AIControlled, Country, DiplomacyChanged, RebelFaction, Relation, Relations, VassalOf,
+};
+use crate::components::opinion::{OpinionModifierKind, Opinions};
+use crate::components::province::Province;
+use crate::plugins::claims::UnjustifiedWar;
+use crate::plugins::peace::province_value;
+use crate::plugins::truces::Truces;
+use crate::plugins::turn::TurnResolutionSet;
+use crate::plugins::vassals::CountryStrength;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// Aggressive expansion for every point of value of a conquered province.
+const EXPANSION_PER_VALUE: f32 = 1.0;
+/// Aggressive expansion for declaring a war without a casus belli.
+const UNJUSTIFIED_WAR_EXPANSION: f32 = 20.0;
+/// Opinion other countries lose per point of aggressive expansion from conquest.
+const EXPANSION_OPINION: f32 = -0.5;
+/// Aggressive expansion every country sheds each turn.
+const EXPANSION_DECAY: f32 = 1.0;
+/// Aggressive expansion at which other countries start banding together.
+pub const COALITION_EXPANSION: f32 = 50.0;
+/// A coalition that is not fighting breaks up once the threat falls below this.
+const COALITION_DISBAND_EXPANSION: f32 = 25.0;
+/// Countries join a coalition against one they think this little of, and leave
+/// once they think better of it than `COALITION_LEAVE_OPINION`.
+const COALITION_OPINION: f32 = -20.0;
+const COALITION_LEAVE_OPINION: f32 = 0.0;
+/// Members it takes before a coalition dares to declare war.
+const MIN_COALITION_MEMBERS: usize = 2;
+
+pub struct CoalitionsPlugin;
+
+impl Plugin for CoalitionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Coalitions>()
+            .add_systems(
+                Update,
+                (ai_join_coalitions, ai_coalition_wars)
+                    .chain()
+                    .in_set(TurnResolutionSet::AIDecision),
+            )
+            .add_systems(Update, update_coalitions.in_set(TurnResolutionSet::End))
+            .add_observer(on_province_conquered)
+            .add_observer(on_unjustified_war_expansion);
+    }
+}
+
+/// Countries banded together against one that threatens them all.
+#[derive(Clone, Debug)]
+pub struct Coalition {
+    pub target: Entity,
+    pub members: Vec<Entity>,
+}
+
+/// Aggressive expansion of every country, and the coalitions formed against them.
+#[derive(Resource, Default)]
+pub struct Coalitions {
+    pub expansion: HashMap<Entity, f32>,
+    pub coalitions: Vec<Coalition>,
+}
+
+impl Coalitions {
+    pub fn expansion(&self, country: Entity) -> f32 {
+        self.expansion.get(&country).copied().unwrap_or(0.0)
+    }
+
+    pub fn add_expansion(&mut self, country: Entity, amount: f32) {
+        *self.expansion.entry(country).or_insert(0.0) += amount;
+    }
+
+    /// The coalition against `target`, if there is one.
+    pub fn against(&self, target: Entity) -> Option<&Coalition> {
+        self.coalitions
+            .iter()
+            .find(|coalition| coalition.target == target)
+    }
+
+    /// Every coalition `country` is a member of.
+    pub fn joined_by(&self, country: Entity) -> impl Iterator<Item = &Coalition> + '_ {
+        self.coalitions
+            .iter()
+            .filter(move |coalition| coalition.members.contains(&country))
+    }
+
+    pub fn join(&mut self, country: Entity, target: Entity) {
+        match self
+            .coalitions
+            .iter_mut()
+            .find(|coalition| coalition.target == target)
+        {
+            Some(coalition) if !coalition.members.contains(&country) => {
+                coalition.members.push(country);
+            }
+            Some(_) => {}
+            None => self.coalitions.push(Coalition {
+                target,
+                members: vec![country],
+            }),
+        }
+    }
+
+    /// Forgets a country that no longer exists, as aggressor and as member.
+    pub fn remove_country(&mut self, country: Entity) {
+        self.expansion.remove(&country);
+        for coalition in &mut self.coalitions {
+            coalition.members.retain(|&member| member != country);
+        }
+        self.coalitions
+            .retain(|coalition| coalition.target != country && !coalition.members.is_empty());
+    }
+}
+
+/// A province has changed hands in a peace deal.
+#[derive(Event)]
+pub struct ProvinceConquered {
+    pub conqueror: Entity,
+    pub province: Entity,
+}
+
+/// Taking land alarms everyone, the more so the richer the land.
+fn on_province_conquered(
+    trigger: On<ProvinceConquered>,
+    mut coalitions: ResMut<Coalitions>,
+    provinces: Query<&Province>,
+    mut opinions: Query<(Entity, &mut Opinions), Without<RebelFaction>>,
+) {
+    let ev = trigger.event();
+
+    let Ok(province) = provinces.get(ev.province) else {
+        return;
+    };
+    let expansion = province_value(province) as f32 * EXPANSION_PER_VALUE;
+    coalitions.add_expansion(ev.conqueror, expansion);
+
+    for (country, mut country_opinions) in &mut opinions {
+        if country != ev.conqueror {
+            country_opinions.add(
+                ev.conqueror,
+                OpinionModifierKind::AggressiveExpansion,
+                expansion * EXPANSION_OPINION,
+            );
+        }
+    }
+}
+
+fn on_unjustified_war_expansion(trigger: On<UnjustifiedWar>, mut coalitions: ResMut<Coalitions>) {
+    let ev = trigger.event();
+    coalitions.add_expansion(ev.declarer, UNJUSTIFIED_WAR_EXPANSION);
+}
+
+/// Fades aggressive expansion, lets members who have made up with the target
+/// leave, and breaks up coalitions that no longer have a reason to exist.
+fn update_coalitions(
+    mut coalitions: ResMut<Coalitions>,
+    countries: Query<(&Relations, &Opinions)>,
+) {
+    for expansion in coalitions.expansion.values_mut() {
+        *expansion = (*expansion - EXPANSION_DECAY).max(0.0);
+    }
+    coalitions.expansion.retain(|_, expansion| *expansion > 0.0);
+
+    let at_war = |member: Entity, target: Entity| {
+        countries
+            .get(member)
+            .is_ok_and(|(relations, _)| relations.get(target) == Relation::War)
+    };
+    let reconciled = |member: Entity, target: Entity| {
+        countries
+            .get(member)
+            .is_ok_and(|(_, opinions)| opinions.get(target) > COALITION_LEAVE_OPINION)
+    };
+
+    let Coalitions {
+        expansion,
+        coalitions,
+    } = &mut *coalitions;
+
+    for coalition in coalitions.iter_mut() {
+        let target = coalition.target;
+        coalition
+            .members
+            .retain(|&member| at_war(member, target) || !reconciled(member, target));
+    }
+    coalitions.retain(|coalition| {
+        let fighting = coalition
+            .members
+            .iter()
+            .any(|&member| at_war(member, coalition.target));
+        let threat = expansion.get(&coalition.target).copied().unwrap_or(0.0);
+
+        !coalition.members.is_empty() && (fighting || threat >= COALITION_DISBAND_EXPANSION)
+    });
+}
+
+/// AI countries join the coalition against any country whose expansion they fear.
+/// Vassals follow their overlord, and no one turns on an ally.
+fn ai_join_coalitions(
+    mut coalitions: ResMut<Coalitions>,
+    ai_countries: Query<
+        (Entity, &Country, &Relations, &Opinions),
+        (With<AIControlled>, Without<VassalOf>),
+    >,
+    countries: Query<&Country>,
+) {
+    let threats: Vec<Entity> = coalitions
+        .expansion
+        .iter()
+        .filter(|&(_, &expansion)| expansion >= COALITION_EXPANSION)
+        .map(|(&country, _)| country)
+        .collect();
+
+    for target in threats {
+        for (country_entity, country, relations, opinions) in &ai_countries {
+            if country_entity == target
+                || relations.get(target) == Relation::Alliance
+                || opinions.get(target) > COALITION_OPINION
+                || coalitions
+                    .against(target)
+                    .is_some_and(|coalition| coalition.members.contains(&country_entity))
+            {
+                continue;
+            }
+
+            coalitions.join(country_entity, target);
+            if let Ok(target_country) = countries.get(target) {
+                println!(
+                    "{} joined the coalition against {}",
+                    country.name, target_country.name
+                );
+            }
+        }
+    }
+}
+
+/// A coalition strong enough to take on its target declares war on it together.
+fn ai_coalition_wars(
+    mut commands: Commands,
+    coalitions: Res<Coalitions>,
+    mut relations: Query<&mut Relations>,
+    truces: Res<Truces>,
+    strength: CountryStrength,
+    countries: Query<&Country>,
+) {
+    for coalition in &coalitions.coalitions {
+        let target = coalition.target;
+        let relation = |member: Entity| {
+            relations
+                .get(member)
+                .map_or(Relation::Peace, |r| r.get(target))
+        };
+
+        if coalition
+            .members
+            .iter()
+            .any(|&member| relation(member) == Relation::War)
+        {
+            continue;
+        }
+
+        let ready: Vec<Entity> = coalition
+            .members
+            .iter()
+            .copied()
+            .filter(|&member| {
+                relation(member) == Relation::Peace && !truces.between(member, target)
+            })
+            .collect();
+
+        if ready.len() < MIN_COALITION_MEMBERS {
+            continue;
+        }
+
+        let combined: i32 = ready.iter().map(|&member| strength.of(member)).sum();
+        if combined < strength.of(target) {
+            continue;
+        }
+
+        for &member in &ready {
+            let Ok([mut member_relations, mut target_relations]) =
+                relations.get_many_mut([member, target])
+            else {
+                continue;
+            };
+            member_relations.set(target, Relation::War);
+            target_relations.set(member, Relation::War);
+
+            commands.trigger(DiplomacyChanged {
+                declarer: member,
+                target,
+                new_relation: Relation::War,
+            });
+        }
+
+        let target_name = countries
+            .get(target)
+            .map_or("Unknown".to_string(), |c| c.name.clone());
+        println!(
+            "A coalition of {} countries declared war on {}",
+            ready.len(),
+            target_name
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::misc::test_entities;
+
+    #[test]
+    fn joining_forms_one_coalition_per_target() {
+        let countries = test_entities(3);
+        let (target, a, b) = (countries[0], countries[1], countries[2]);
+        let mut coalitions = Coalitions::default();
+        coalitions.join(a, target);
+        coalitions.join(b, target);
+        coalitions.join(a, target);
+
+        assert_eq!(coalitions.coalitions.len(), 1);
+        let members = coalitions.against(target).map(|c| c.members.clone());
+        assert_eq!(members, Some(vec![a, b]));
+        assert_eq!(coalitions.joined_by(a).count(), 1);
+    }
+
+    #[test]
+    fn removed_country_leaves_every_coalition() {
+        let countries = test_entities(3);
+        let (target, a, b) = (countries[0], countries[1], countries[2]);
+        let mut coalitions = Coalitions::default();
+        coalitions.join(a, target);
+        coalitions.join(b, target);
+        coalitions.join(target, a);
+        coalitions.add_expansion(target, 30.0);
+
+        coalitions.remove_country(target);
+
+        assert!(coalitions.against(target).is_none());
+        assert_eq!(coalitions.joined_by(target).count(), 0);
+        assert_eq!(coalitions.expansion(target), 0.0);
+        assert!(coalitions.against(a).is_none());
+    }
+}
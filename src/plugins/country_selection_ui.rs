@@ -1,6 +1,7 @@
 // plugins/country_selection_ui.rs
-use crate::components::country::{AIControlled, Country};
+use crate::components::country::{AIControlled, Country, RebelFaction};
 use crate::components::player::{ControlsCountry, LocalPlayer, Player};
+use crate::misc::difficulty::{ALL_DIFFICULTIES, Difficulty};
 use crate::states::AppState;
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
@@ -29,8 +30,9 @@ fn cleanup_countries(mut commands: Commands, countries: Query<Entity, With<Count
 fn country_selection_ui(
     mut commands: Commands,
     mut contexts: EguiContexts,
-    countries: Query<(Entity, &Country)>,
+    countries: Query<(Entity, &Country), Without<RebelFaction>>,
     mut next_state: ResMut<NextState<AppState>>,
+    mut difficulty: ResMut<Difficulty>,
 ) {
     let Ok(ctx) = contexts.ctx_mut() else {
         return;
@@ -39,6 +41,14 @@ fn country_selection_ui(
     egui::CentralPanel::default().show(ctx, |ui| {
         ui.vertical_centered(|ui| {
             ui.heading("Select Your Country");
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                ui.label("Difficulty:");
+                for level in ALL_DIFFICULTIES {
+                    ui.selectable_value(&mut *difficulty, level, level.name());
+                }
+            });
             ui.add_space(20.0);
 
             egui::ScrollArea::vertical().show(ui, |ui| {
@@ -1,8 +1,24 @@
-use crate::components::country::{Country, DiplomacyChanged, Relation, Relations};
+use crate::components::country::{
+    Country, DiplomacyChanged, Grievances, Relation, Relations, VassalOf,
+};
+use crate::components::opinion::{OpinionModifierKind, Opinions};
+use crate::components::personality::Personality;
+use crate::components::player::{ControlsCountry, LocalPlayer};
 use crate::components::province::{Occupied, OwnedBy};
 use crate::misc::CommandsAndContexts;
 use crate::misc::PlayerParams;
+use crate::plugins::alliances::LEFT_ALLIANCE_GRIEVANCE;
+use crate::plugins::claims::{FABRICATION_TURNS, FabricateClaim, UnjustifiedWar, WarGoals};
+use crate::plugins::coalitions::{COALITION_EXPANSION, Coalitions};
+use crate::plugins::ledger::{BudgetLedger, ExpenseKind};
+use crate::plugins::military_access::MilitaryAccess;
+use crate::plugins::opinion::{GIFT_GOLD, GIFT_OPINION};
+use crate::plugins::peace::PeaceNegotiation;
+use crate::plugins::proposals::{ProposalKind, Proposals};
 use crate::plugins::selection::{CurrentSelection, SelectedEntity};
+use crate::plugins::truces::Truces;
+use crate::plugins::vassals::{declare_independence, release_vassal};
+use crate::plugins::war_score::Wars;
 use crate::states::AppState;
 use bevy::prelude::*;
 use bevy_egui::{EguiPrimaryContextPass, egui};
@@ -17,7 +33,8 @@ impl Plugin for DiplomacyPlugin {
                 EguiPrimaryContextPass,
                 diplomacy_window.run_if(in_state(AppState::InGame)),
             )
-            .add_observer(on_peace_transfer_occupations);
+            .add_observer(on_peace_end_occupations)
+            .add_observer(on_diplomacy_play_sound);
     }
 }
 
@@ -34,11 +51,11 @@ fn setup_audio(mut commands: Commands, asset_server: Res<AssetServer>) {
     });
 }
 
-fn on_peace_transfer_occupations(
+/// Peace terms decide who gets which land; whatever else was occupied goes back to its owner.
+fn on_peace_end_occupations(
     trigger: On<DiplomacyChanged>,
     mut commands: Commands,
-    mut provinces: Query<(Entity, &mut OwnedBy, &Occupied)>,
-    relations: Query<&Relations>,
+    provinces: Query<(Entity, &OwnedBy, &Occupied)>,
 ) {
     let ev = trigger.event();
 
@@ -48,33 +65,72 @@ fn on_peace_transfer_occupations(
 
     let peace_parties = [ev.declarer, ev.target];
 
-    for (prov_entity, mut owned_by, occupied) in &mut provinces {
-        let occupier = occupied.occupier;
-
-        if !peace_parties.contains(&occupier) {
-            continue;
-        }
-
-        if let Ok(occupier_rels) = relations.get(occupier)
-            && occupier_rels.get(owned_by.owner) == Relation::Peace
-        {
-            let _old_owner = owned_by.owner;
-            owned_by.owner = occupier;
+    for (prov_entity, owned_by, occupied) in &provinces {
+        if peace_parties.contains(&occupied.occupier) && peace_parties.contains(&owned_by.owner) {
             commands.entity(prov_entity).remove::<Occupied>();
         }
     }
 }
 
+fn on_diplomacy_play_sound(
+    trigger: On<DiplomacyChanged>,
+    mut commands: Commands,
+    audio: Res<DiplomacyAudio>,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+) {
+    let ev = trigger.event();
+
+    let Some(player_country) = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0)
+    else {
+        return;
+    };
+
+    if ev.declarer != player_country && ev.target != player_country {
+        return;
+    }
+
+    let sound = match ev.new_relation {
+        Relation::War => &audio.war_sound,
+        Relation::Peace | Relation::Alliance => &audio.peace_sound,
+    };
+
+    commands.spawn(AudioPlayer::new(sound.clone()));
+}
+
+/// Changes to the bond between the player and the selected country as overlord and vassal.
+#[derive(Clone, Copy)]
+enum VassalAction {
+    Release,
+    DeclareIndependence,
+}
+
 fn diplomacy_window(
     commands_and_contexts: CommandsAndContexts,
-    current_selection: Res<CurrentSelection>,
-    provinces: Query<&OwnedBy>,
-    countries: Query<&Country>,
-    mut relations_q: Query<&mut Relations>,
+    (current_selection, provinces, personalities): (
+        Res<CurrentSelection>,
+        Query<&OwnedBy>,
+        Query<&Personality>,
+    ),
+    mut countries: Query<&mut Country>,
+    mut relations_q: Query<(&mut Relations, &mut Grievances, &mut Opinions)>,
     player_params: PlayerParams,
+    (mut ledger, wars, truces, mut access): (
+        ResMut<BudgetLedger>,
+        Res<Wars>,
+        Res<Truces>,
+        ResMut<MilitaryAccess>,
+    ),
+    (vassals, war_goals, mut proposals, coalitions): (
+        Query<&VassalOf>,
+        WarGoals,
+        ResMut<Proposals>,
+        Res<Coalitions>,
+    ),
     //local_player: Res<LocalPlayer>,
     //player_controls: Query<&ControlsCountry>,
-    audio: Res<DiplomacyAudio>,
 ) {
     let mut commands = commands_and_contexts.commands;
     let mut contexts = commands_and_contexts.contexts;
@@ -102,6 +158,7 @@ fn diplomacy_window(
     let Ok(selected_country) = countries.get(selected_country_entity) else {
         return;
     };
+    let selected_name = selected_country.name.clone();
 
     let player_entity = local_player.0;
     let Ok(player_control) = player_controls.get(player_entity) else {
@@ -113,77 +170,444 @@ fn diplomacy_window(
         return;
     }
 
-    egui::Window::new(format!("Diplomacy – {}", selected_country.name))
+    let player_gold = countries
+        .get(player_country_entity)
+        .map_or(0, |country| country.gold);
+    let mut send_gift = false;
+    let mut vassal_action = None;
+    let casus_belli = war_goals.casus_belli(player_country_entity, selected_country_entity);
+
+    let overlord_of = |country: Entity| vassals.get(country).ok().map(|v| v.overlord);
+    let is_overlord = |country: Entity| vassals.iter().any(|v| v.overlord == country);
+    let our_vassal = overlord_of(selected_country_entity) == Some(player_country_entity);
+    let our_overlord = overlord_of(player_country_entity) == Some(selected_country_entity);
+
+    egui::Window::new(format!("Diplomacy – {}", selected_name))
         .anchor(egui::Align2::RIGHT_TOP, [20.0, 60.0])
         .resizable(false)
         .collapsible(false)
         .default_size([280.0, 160.0])
         .show(ctx, |ui| {
             ui.vertical_centered(|ui| {
-                ui.heading(&selected_country.name);
+                ui.heading(&selected_name);
+                if let Ok(personality) = personalities.get(selected_country_entity) {
+                    ui.label(format!("Personality: {}", personality.name()));
+                }
                 ui.add_space(8.0);
 
-                if let Ok(mut player_relations) = relations_q.get_mut(player_country_entity) {
-                    let current = player_relations.get(selected_country_entity);
+                let Ok(
+                    [
+                        (player_relations, ..),
+                        (selected_relations, _, selected_opinions),
+                    ],
+                ) = relations_q.get_many([player_country_entity, selected_country_entity])
+                else {
+                    ui.colored_label(egui::Color32::RED, "No diplomatic relations set");
+                    return;
+                };
 
-                    let status_text = match current {
-                        Relation::Peace => {
-                            egui::RichText::new("Peace").color(egui::Color32::LIGHT_GREEN)
-                        }
-                        Relation::War => egui::RichText::new("At War").color(egui::Color32::RED),
-                    };
+                let current = player_relations.get(selected_country_entity);
+
+                let status_text = match current {
+                    Relation::Peace => {
+                        egui::RichText::new("Peace").color(egui::Color32::LIGHT_GREEN)
+                    }
+                    Relation::War => egui::RichText::new("At War").color(egui::Color32::RED),
+                    Relation::Alliance => {
+                        egui::RichText::new("Allied").color(egui::Color32::LIGHT_BLUE)
+                    }
+                };
+
+                ui.label(status_text);
+
+                if our_vassal {
+                    ui.label("Our vassal");
+                } else if our_overlord {
+                    ui.label("Our overlord");
+                } else if let Some(overlord) = overlord_of(selected_country_entity)
+                    && let Ok(overlord_country) = countries.get(overlord)
+                {
+                    ui.label(format!("Vassal of {}", overlord_country.name));
+                }
+
+                let truce = truces.turns_left(player_country_entity, selected_country_entity);
+                if let Some(turns_left) = truce {
+                    ui.label(format!("Truce: {} turns left", turns_left));
+                }
 
-                    ui.label(status_text);
-                    ui.add_space(12.0);
+                let allies: Vec<&str> = selected_relations
+                    .allies()
+                    .filter_map(|ally| countries.get(ally).ok())
+                    .map(|ally| ally.name.as_str())
+                    .collect();
+                if allies.is_empty() {
+                    ui.label("No allies");
+                } else {
+                    ui.label(format!("Allies: {}", allies.join(", ")));
+                }
+
+                let expansion_label = |ui: &mut egui::Ui, text: &str, expansion: f32| {
+                    if expansion >= COALITION_EXPANSION {
+                        ui.colored_label(
+                            egui::Color32::LIGHT_RED,
+                            format!("{}: {:.0}", text, expansion),
+                        );
+                    } else if expansion > 0.0 {
+                        ui.label(format!("{}: {:.0}", text, expansion));
+                    }
+                };
+                expansion_label(
+                    ui,
+                    "Aggressive expansion",
+                    coalitions.expansion(selected_country_entity),
+                );
+                expansion_label(
+                    ui,
+                    "Our aggressive expansion",
+                    coalitions.expansion(player_country_entity),
+                );
+
+                if let Some(coalition) = coalitions.against(selected_country_entity) {
+                    let members: Vec<&str> = coalition
+                        .members
+                        .iter()
+                        .filter_map(|&member| countries.get(member).ok())
+                        .map(|member| member.name.as_str())
+                        .collect();
+                    ui.label(format!("Coalition against them: {}", members.join(", ")));
+                }
+                for coalition in coalitions.joined_by(selected_country_entity) {
+                    if coalition.target == player_country_entity {
+                        ui.colored_label(egui::Color32::LIGHT_RED, "In the coalition against us");
+                    } else if let Ok(target) = countries.get(coalition.target) {
+                        ui.label(format!("In the coalition against {}", target.name));
+                    }
+                }
 
-                    let button_text = match current {
-                        Relation::Peace => "Declare War",
-                        Relation::War => "Propose Peace",
+                if let Some(war) = wars.get(player_country_entity, selected_country_entity) {
+                    let score = war.score(player_country_entity);
+                    let score_color = if score.total() >= 0 {
+                        egui::Color32::LIGHT_GREEN
+                    } else {
+                        egui::Color32::LIGHT_RED
                     };
+                    egui::CollapsingHeader::new(
+                        egui::RichText::new(format!("War score: {:+}", score.total()))
+                            .color(score_color),
+                    )
+                    .id_salt("war_score")
+                    .show(ui, |ui| {
+                        ui.label(format!("Occupations: {:+}", score.occupation));
+                        ui.label(format!(
+                            "Battles: {:+} ({} won, {} lost)",
+                            score.battles,
+                            war.battles_won(player_country_entity),
+                            war.battles_lost(player_country_entity)
+                        ));
+                        ui.label(format!("Holding ground: {:+}", score.ticking));
+                        ui.label(format!("Turns at war: {}", war.turns));
+                        ui.label(format!(
+                            "Our war exhaustion: {:.0}",
+                            war.exhaustion(player_country_entity)
+                        ));
+                        ui.label(format!(
+                            "Their war exhaustion: {:.0}",
+                            war.exhaustion(selected_country_entity)
+                        ));
+                    });
+                }
+
+                let opinion = selected_opinions.get(player_country_entity);
+                let opinion_color = if opinion >= 0.0 {
+                    egui::Color32::LIGHT_GREEN
+                } else {
+                    egui::Color32::LIGHT_RED
+                };
+                egui::CollapsingHeader::new(
+                    egui::RichText::new(format!("Opinion of us: {:+.0}", opinion))
+                        .color(opinion_color),
+                )
+                .id_salt("opinion_modifiers")
+                .show(ui, |ui| {
+                    let modifiers = selected_opinions.modifiers(player_country_entity);
+                    if modifiers.is_empty() {
+                        ui.label("No opinion modifiers");
+                    }
+                    for modifier in modifiers {
+                        ui.label(format!("{}: {:+.0}", modifier.kind.name(), modifier.value));
+                    }
+                });
+
+                let they_grant = access.has(selected_country_entity, player_country_entity);
+                let we_grant = access.has(player_country_entity, selected_country_entity);
+                ui.label(match (they_grant, we_grant) {
+                    (true, true) => "Military access: both ways",
+                    (true, false) => "Military access: granted to us",
+                    (false, true) => "Military access: granted to them",
+                    (false, false) => "Military access: none",
+                });
+
+                ui.add_space(12.0);
+
+                if current == Relation::Peace {
+                    if !they_grant {
+                        let requested = proposals.is_pending(
+                            player_country_entity,
+                            selected_country_entity,
+                            &ProposalKind::MilitaryAccess,
+                        );
+                        let text = if requested {
+                            "Military Access Requested"
+                        } else {
+                            "Request Military Access"
+                        };
+                        if ui
+                            .add_enabled(!requested, egui::Button::new(text))
+                            .clicked()
+                        {
+                            proposals.send(
+                                player_country_entity,
+                                selected_country_entity,
+                                ProposalKind::MilitaryAccess,
+                            );
+                        }
+                    }
+
+                    if we_grant {
+                        if ui.button("Revoke Military Access").clicked() {
+                            access.revoke(player_country_entity, selected_country_entity);
+                        }
+                    } else if ui.button("Grant Military Access").clicked() {
+                        access.grant(player_country_entity, selected_country_entity);
+                    }
+                }
+
+                if current == Relation::Peace {
+                    match casus_belli {
+                        Some(casus_belli) => {
+                            ui.label(format!("Casus belli: {}", casus_belli.name()));
+                        }
+                        None => {
+                            ui.colored_label(egui::Color32::LIGHT_RED, "Casus belli: none");
+                        }
+                    }
+
+                    if let Some(fabrication) = war_goals.claims().fabricating(player_country_entity)
+                    {
+                        ui.label(format!(
+                            "Fabricating a claim: {} turns left",
+                            fabrication.turns_left
+                        ));
+                    } else if !war_goals.claims_province(player_country_entity, selected_province)
+                        && ui
+                            .button(format!(
+                                "Fabricate Claim on this Province ({} turns)",
+                                FABRICATION_TURNS
+                            ))
+                            .clicked()
+                    {
+                        commands.trigger(FabricateClaim {
+                            claimant: player_country_entity,
+                            province: selected_province,
+                        });
+                    }
+                }
+
+                let declare_war_text = if casus_belli.is_some() {
+                    "Declare War"
+                } else {
+                    "Declare War (no casus belli)"
+                };
 
-                    let button_color = match current {
-                        Relation::Peace => egui::Color32::from_rgb(180, 40, 40),
-                        Relation::War => egui::Color32::from_rgb(60, 140, 60),
+                // (button text, colour, relation it leads to)
+                let actions: Vec<(&str, egui::Color32, Relation)> = match current {
+                    Relation::Peace => vec![
+                        (
+                            declare_war_text,
+                            egui::Color32::from_rgb(180, 40, 40),
+                            Relation::War,
+                        ),
+                        (
+                            "Propose Alliance",
+                            egui::Color32::from_rgb(50, 90, 160),
+                            Relation::Alliance,
+                        ),
+                    ],
+                    Relation::War => vec![(
+                        "Negotiate Peace",
+                        egui::Color32::from_rgb(60, 140, 60),
+                        Relation::Peace,
+                    )],
+                    // Vassals and overlords part ways through the vassal buttons below.
+                    Relation::Alliance if our_vassal || our_overlord => vec![],
+                    Relation::Alliance => vec![(
+                        "Leave Alliance",
+                        egui::Color32::from_rgb(120, 100, 40),
+                        Relation::Peace,
+                    )],
+                };
+
+                let alliance_proposed = proposals.is_pending(
+                    player_country_entity,
+                    selected_country_entity,
+                    &ProposalKind::Alliance,
+                );
+
+                let mut chosen = None;
+                for (text, color, new_relation) in actions {
+                    // War cannot be declared while a truce runs, nor an alliance proposed twice.
+                    let enabled = match new_relation {
+                        Relation::War => truce.is_none(),
+                        Relation::Alliance => !alliance_proposed,
+                        Relation::Peace => true,
                     };
+                    if ui
+                        .add_enabled(enabled, egui::Button::new(text).fill(color))
+                        .clicked()
+                    {
+                        chosen = Some(new_relation);
+                    }
+                }
 
+                if current == Relation::Peace
+                    && overlord_of(player_country_entity).is_none()
+                    && overlord_of(selected_country_entity).is_none()
+                    && !is_overlord(selected_country_entity)
+                {
+                    let offered = proposals.is_pending(
+                        player_country_entity,
+                        selected_country_entity,
+                        &ProposalKind::Vassalage,
+                    );
                     if ui
-                        .add(egui::Button::new(button_text).fill(button_color))
+                        .add_enabled(!offered, egui::Button::new("Offer Vassalage"))
                         .clicked()
                     {
-                        let new_relation = match current {
-                            Relation::Peace => Relation::War,
-                            Relation::War => Relation::Peace,
-                        };
+                        proposals.send(
+                            player_country_entity,
+                            selected_country_entity,
+                            ProposalKind::Vassalage,
+                        );
+                    }
+                }
+                if our_vassal && ui.button("Release Vassal").clicked() {
+                    vassal_action = Some(VassalAction::Release);
+                }
+                if our_overlord
+                    && ui
+                        .add_enabled(
+                            truce.is_none(),
+                            egui::Button::new("Declare Independence")
+                                .fill(egui::Color32::from_rgb(180, 40, 40)),
+                        )
+                        .clicked()
+                {
+                    vassal_action = Some(VassalAction::DeclareIndependence);
+                }
 
-                        let sound = match new_relation {
-                            Relation::War => &audio.war_sound,
-                            Relation::Peace => &audio.peace_sound,
-                        };
+                if ui
+                    .add_enabled(
+                        player_gold >= GIFT_GOLD,
+                        egui::Button::new(format!("Send Gift ({} gold)", GIFT_GOLD)),
+                    )
+                    .clicked()
+                {
+                    send_gift = true;
+                }
 
-                        commands.spawn(AudioPlayer::new(sound.clone()));
+                let Some(new_relation) = chosen else {
+                    return;
+                };
 
-                        player_relations.set(selected_country_entity, new_relation);
+                if current == Relation::War {
+                    commands.insert_resource(PeaceNegotiation::new(selected_country_entity));
+                    return;
+                }
 
-                        if let Ok(mut target_relations) =
-                            relations_q.get_mut(selected_country_entity)
-                        {
-                            target_relations.set(player_country_entity, new_relation);
-                        }
+                if new_relation == Relation::Alliance {
+                    proposals.send(
+                        player_country_entity,
+                        selected_country_entity,
+                        ProposalKind::Alliance,
+                    );
+                    println!("Proposed an alliance to {}", selected_name);
+                    return;
+                }
 
-                        commands.trigger(DiplomacyChanged {
-                            declarer: player_country_entity,
-                            target: selected_country_entity,
-                            new_relation,
-                        });
+                if let Ok((mut player_relations, ..)) = relations_q.get_mut(player_country_entity) {
+                    player_relations.set(selected_country_entity, new_relation);
+                }
 
-                        println!(
-                            "Diplomacy changed: {} ↔ {} → {:?}",
-                            selected_country.name, player_country_entity, new_relation
-                        );
+                if let Ok((mut target_relations, mut target_grievances, _)) =
+                    relations_q.get_mut(selected_country_entity)
+                {
+                    target_relations.set(player_country_entity, new_relation);
+
+                    if current == Relation::Alliance {
+                        target_grievances.add(player_country_entity, LEFT_ALLIANCE_GRIEVANCE);
                     }
-                } else {
-                    ui.colored_label(egui::Color32::RED, "No diplomatic relations set");
                 }
+
+                commands.trigger(DiplomacyChanged {
+                    declarer: player_country_entity,
+                    target: selected_country_entity,
+                    new_relation,
+                });
+                if new_relation == Relation::War && casus_belli.is_none() {
+                    commands.trigger(UnjustifiedWar {
+                        declarer: player_country_entity,
+                        target: selected_country_entity,
+                    });
+                }
+
+                println!(
+                    "Diplomacy changed: {} ↔ {} → {:?}",
+                    selected_name, player_country_entity, new_relation
+                );
             });
         });
+
+    if send_gift
+        && let Ok([mut player_country, mut selected_country]) =
+            countries.get_many_mut([player_country_entity, selected_country_entity])
+        && player_country.gold >= GIFT_GOLD
+    {
+        player_country.gold -= GIFT_GOLD;
+        selected_country.gold += GIFT_GOLD;
+        ledger.record_expense(player_country_entity, ExpenseKind::Gifts, GIFT_GOLD);
+        ledger.record_gift_income(selected_country_entity, GIFT_GOLD);
+
+        if let Ok((_, _, mut opinions)) = relations_q.get_mut(selected_country_entity) {
+            opinions.add(
+                player_country_entity,
+                OpinionModifierKind::Gift,
+                GIFT_OPINION,
+            );
+        }
+
+        println!("Sent a gift of {} gold to {}", GIFT_GOLD, selected_name);
+    }
+
+    let Some(action) = vassal_action else {
+        return;
+    };
+
+    let Ok([(mut player_relations, ..), (mut selected_relations, ..)]) =
+        relations_q.get_many_mut([player_country_entity, selected_country_entity])
+    else {
+        return;
+    };
+    let player = (player_country_entity, &mut *player_relations);
+    let selected = (selected_country_entity, &mut *selected_relations);
+
+    match action {
+        VassalAction::Release => {
+            release_vassal(&mut commands, selected, player);
+            println!("Released {} from vassalage", selected_name);
+        }
+        VassalAction::DeclareIndependence => {
+            declare_independence(&mut commands, player, selected);
+            println!("Declared independence from {}", selected_name);
+        }
+    }
 }
@@ -1,19 +1,34 @@
 // ui/events_ui.rs
 use crate::components::army::Army;
-use crate::components::country::Country;
-use crate::components::events::{EventEffect, PendingEvent};
+use crate::components::country::{Country, DiplomacyChanged, Grievances, Relation, Relations};
+use crate::components::debt::Debt;
+use crate::components::events::{EventEffect, PendingEvent, QueuedEvents};
 use crate::components::player::ControlsCountry;
 use crate::components::province::{OwnedBy, Province};
+use crate::misc::unrest::apply_unrest_change;
 use crate::misc::{CommandsAndContexts, PlayerParams};
+use crate::plugins::alliances::REFUSED_CALL_GRIEVANCE;
+use crate::plugins::claims::Claims;
+use crate::plugins::ledger::{BudgetLedger, ExpenseKind};
 use crate::states::GamePhase;
 use bevy::prelude::*;
 use bevy_egui::{EguiPrimaryContextPass, egui};
 
+/// Unrest added to every province when an event cost cannot be paid or borrowed.
+const UNPAID_EVENT_UNREST: f32 = 10.0;
+
 pub struct EventUIPlugin;
 
+type EventCountryQuery<'a> = (
+    &'a mut Country,
+    &'a mut Debt,
+    &'a mut Relations,
+    &'a mut Grievances,
+);
+
 impl Plugin for EventUIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<QueuedEvents>().add_systems(
             EguiPrimaryContextPass,
             show_event_ui.run_if(in_state(GamePhase::Event)),
         );
@@ -22,12 +37,16 @@ impl Plugin for EventUIPlugin {
 
 fn show_event_ui(
     commands_and_contexts: CommandsAndContexts,
-    pending_event: Res<PendingEvent>,
-    mut next_state: ResMut<NextState<GamePhase>>,
+    (pending_event, mut queued_events, mut next_state): (
+        Res<PendingEvent>,
+        ResMut<QueuedEvents>,
+        ResMut<NextState<GamePhase>>,
+    ),
     player_params: PlayerParams,
-    mut countries: Query<&mut Country>,
+    mut countries: Query<EventCountryQuery>,
     mut armies: Query<&mut Army>,
     mut provinces: Query<(&mut Province, &OwnedBy)>,
+    (mut ledger, mut claims): (ResMut<BudgetLedger>, ResMut<Claims>),
 ) {
     let mut commands = commands_and_contexts.commands;
     let mut contexts = commands_and_contexts.contexts;
@@ -59,18 +78,26 @@ fn show_event_ui(
 
             for (i, option) in event.options.iter().enumerate() {
                 if ui.button(&option.description).clicked() {
-                    // Apply the effect
-                    apply_event_effect(
-                        &option.effect,
-                        player_entity,
-                        &player_controls,
-                        &mut countries,
-                        &mut armies,
-                        &mut provinces,
-                    );
-
-                    commands.remove_resource::<PendingEvent>();
-                    next_state.set(GamePhase::PlayerTurn);
+                    // Apply the effects
+                    for effect in &option.effects {
+                        apply_event_effect(
+                            effect,
+                            player_entity,
+                            &player_controls,
+                            &mut countries,
+                            &mut armies,
+                            &mut provinces,
+                            (&mut commands, &mut ledger, &mut claims),
+                        );
+                    }
+
+                    // Events that came up meanwhile are shown next, before the turn goes on.
+                    if let Some(next) = queued_events.events.pop_front() {
+                        commands.insert_resource(PendingEvent::new(next));
+                    } else {
+                        commands.remove_resource::<PendingEvent>();
+                        next_state.set(GamePhase::PlayerTurn);
+                    }
                 }
 
                 if i < event.options.len() - 1 {
@@ -84,9 +111,10 @@ fn apply_event_effect(
     effect: &EventEffect,
     player_entity: Entity,
     player_controls: &Query<&ControlsCountry>,
-    countries: &mut Query<&mut Country>,
+    countries: &mut Query<EventCountryQuery>,
     armies: &mut Query<&mut Army>,
     provinces: &mut Query<(&mut Province, &OwnedBy)>,
+    (commands, ledger, claims): (&mut Commands, &mut BudgetLedger, &mut Claims),
 ) {
     println!("Applying effect {:?}", effect);
 
@@ -98,9 +126,33 @@ fn apply_event_effect(
 
     match effect {
         EventEffect::PayGold(amount) | EventEffect::LoseGold(amount) => {
-            if let Ok(mut country) = countries.get_mut(player_country_entity) {
-                country.gold = country.gold.saturating_sub(*amount);
-                println!("Lost {} gold", amount);
+            if let Ok((mut country, mut debt, ..)) = countries.get_mut(player_country_entity) {
+                let paid = (*amount).min(country.gold);
+                country.gold -= paid;
+
+                let shortfall = *amount - paid;
+                if shortfall == 0 {
+                    ledger.record_expense(player_country_entity, ExpenseKind::Events, paid);
+                } else if debt.can_borrow() {
+                    debt.take_loan(shortfall);
+                    ledger.record_borrowing(player_country_entity, shortfall);
+                    ledger.record_expense(player_country_entity, ExpenseKind::Events, *amount);
+                    println!("Borrowed {} gold to cover the shortfall", shortfall);
+                } else {
+                    // No lender will cover it, so the debt goes unpaid and
+                    // the provinces bear the cost instead.
+                    debt.missed_payments += 1;
+                    ledger.record_expense(player_country_entity, ExpenseKind::Events, paid);
+                    for (mut province, owned_by) in provinces.iter_mut() {
+                        if owned_by.owner == player_country_entity {
+                            province.unrest =
+                                apply_unrest_change(province.unrest, UNPAID_EVENT_UNREST);
+                        }
+                    }
+                    println!("Could not cover {} gold, unrest rises", shortfall);
+                }
+
+                println!("Lost {} gold", paid);
             } else {
                 println!(
                     "Warning: player country entity {:?} not found",
@@ -110,8 +162,9 @@ fn apply_event_effect(
         }
 
         EventEffect::GainGold(amount) => {
-            if let Ok(mut country) = countries.get_mut(player_country_entity) {
+            if let Ok((mut country, ..)) = countries.get_mut(player_country_entity) {
                 country.gold += *amount;
+                ledger.record_event_income(player_country_entity, *amount);
                 println!("Gained {} gold", amount);
             }
         }
@@ -135,5 +188,70 @@ fn apply_event_effect(
             }
             println!("Lost {:.0}% population", *percentage * 100.0);
         }
+
+        EventEffect::ChangeUnrest(change) => {
+            for (mut province, owned_by) in provinces.iter_mut() {
+                if owned_by.owner == player_country_entity {
+                    province.unrest = apply_unrest_change(province.unrest, *change);
+                }
+            }
+            println!("Unrest changed by {:+.0} in all provinces", change);
+        }
+
+        EventEffect::JoinWar { ally, enemy } => {
+            let (ally_name, enemy_name) = (
+                country_name(countries, *ally),
+                country_name(countries, *enemy),
+            );
+
+            // A call answered late may come after the ally has made peace.
+            let ally_at_war = countries
+                .get(*ally)
+                .is_ok_and(|(_, _, relations, _)| relations.get(*enemy) == Relation::War);
+            if !ally_at_war {
+                println!("The war of {} against {} is over", ally_name, enemy_name);
+                return;
+            }
+            if let Ok((_, _, mut relations, _)) = countries.get_mut(player_country_entity) {
+                relations.set(*enemy, Relation::War);
+            }
+            if let Ok((_, _, mut relations, _)) = countries.get_mut(*enemy) {
+                relations.set(player_country_entity, Relation::War);
+            }
+            commands.trigger(DiplomacyChanged {
+                declarer: player_country_entity,
+                target: *enemy,
+                new_relation: Relation::War,
+            });
+            println!("Joined the war of {} against {}", ally_name, enemy_name);
+        }
+
+        EventEffect::RefuseCallToArms { ally } => {
+            if let Ok((_, _, mut relations, _)) = countries.get_mut(player_country_entity) {
+                relations.set(*ally, Relation::Peace);
+            }
+            if let Ok((_, _, mut relations, mut grievances)) = countries.get_mut(*ally) {
+                relations.set(player_country_entity, Relation::Peace);
+                grievances.add(player_country_entity, REFUSED_CALL_GRIEVANCE);
+            }
+            println!(
+                "Refused the call to arms of {}, the alliance is over",
+                country_name(countries, *ally)
+            );
+        }
+
+        EventEffect::GainClaim { province } => {
+            claims.add(player_country_entity, *province);
+            if let Ok((province, _)) = provinces.get(*province) {
+                println!("Gained a claim on province {}", province.id);
+            }
+        }
     }
 }
+
+fn country_name(countries: &Query<EventCountryQuery>, country: Entity) -> String {
+    countries.get(country).map_or_else(
+        |_| "an unknown country".to_string(),
+        |(c, ..)| c.name.clone(),
+    )
+}
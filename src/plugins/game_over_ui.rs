@@ -0,0 +1,95 @@
+// plugins/game_over_ui.rs
+use crate::plugins::victory::{CountryStats, GameOutcome};
+use crate::states::AppState;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+pub struct GameOverUI;
+
+impl Plugin for GameOverUI {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            EguiPrimaryContextPass,
+            game_over_ui.run_if(in_state(AppState::GameOver)),
+        );
+    }
+}
+
+fn stats_label(ui: &mut egui::Ui, stats: &CountryStats) {
+    ui.label(format!(
+        "Battles won: {}  Battles lost: {}",
+        stats.battles_won, stats.battles_lost
+    ));
+    ui.label(format!(
+        "Provinces gained: {}  Provinces lost: {}",
+        stats.provinces_gained, stats.provinces_lost
+    ));
+}
+
+fn game_over_ui(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    outcome: Option<Res<GameOutcome>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+    let Some(outcome) = outcome else {
+        return;
+    };
+
+    egui::Window::new(&outcome.title)
+        .id(egui::Id::new("game_over"))
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .collapsible(false)
+        .resizable(false)
+        .show(ctx, |ui| {
+            ui.heading(&outcome.reason);
+            ui.label(format!("Turns played: {}", outcome.turns));
+
+            if let Some((name, stats)) = &outcome.player {
+                ui.separator();
+                ui.strong(name);
+                stats_label(ui, stats);
+            }
+
+            ui.separator();
+            ui.strong("Final standings");
+            egui::Grid::new("game_over_standings")
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("Country");
+                    ui.label("Score");
+                    ui.label("Provinces");
+                    ui.label("Gold");
+                    ui.label("Battles won");
+                    ui.end_row();
+
+                    for standing in &outcome.standings {
+                        ui.label(&standing.name);
+                        ui.label(standing.score.to_string());
+                        ui.label(standing.provinces.to_string());
+                        ui.label(standing.gold.to_string());
+                        ui.label(standing.stats.battles_won.to_string());
+                        ui.end_row();
+                    }
+                });
+
+            if !outcome.eliminated.is_empty() {
+                ui.separator();
+                ui.strong("Eliminated");
+                for (name, turn) in &outcome.eliminated {
+                    ui.label(format!("{} (turn {})", name, turn));
+                }
+            }
+
+            ui.add_space(10.0);
+            ui.vertical_centered(|ui| {
+                if ui.button("Main Menu").clicked() {
+                    commands.remove_resource::<GameOutcome>();
+                    next_state.set(AppState::InMainMenu);
+                }
+            });
+        });
+}
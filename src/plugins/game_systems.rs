@@ -1,3 +1,7 @@
+use crate::components::army::Army;
+use crate::components::game_world_entity::GameWorldEntity;
+use crate::components::player::{LocalPlayer, Player};
+use crate::components::province::Province;
 use crate::plugins::map_generation::MapGenerationPlugin;
 use crate::plugins::*;
 use crate::states::{AppState, GamePhase, PendingMoves};
@@ -14,6 +18,10 @@ impl Plugin for GameSystems {
             .insert_resource(ClearColor(Color::srgb_u8(30, 30, 30)))
             .add_systems(Startup, setup)
             .add_systems(OnEnter(AppState::InGame), clear_cameras)
+            .add_systems(
+                OnExit(AppState::GameOver),
+                (clear_game_world, clear_cameras, setup).chain(),
+            )
             // Game Plugins
             .add_plugins(Terrain3DVisualsPlugin)
             .add_plugins(MapGenerationPlugin)
@@ -26,6 +34,22 @@ impl Plugin for GameSystems {
             .add_plugins(ArmyMovementPlugin)
             .add_plugins(TurnPlugin)
             .add_plugins(DiplomacyPlugin)
+            .add_plugins(AlliancesPlugin)
+            .add_plugins(PeacePlugin)
+            .add_plugins(OpinionPlugin)
+            .add_plugins(WarScorePlugin)
+            .add_plugins(TrucesPlugin)
+            .add_plugins(MilitaryAccessPlugin)
+            .add_plugins(VassalsPlugin)
+            .add_plugins(ClaimsPlugin)
+            .add_plugins(CoalitionsPlugin)
+            .add_plugins(ProposalsPlugin)
+            .add_plugins(TradePlugin)
+            .add_plugins(LedgerPlugin)
+            .add_plugins(LoansPlugin)
+            .add_plugins(UnrestPlugin)
+            .add_plugins(LootingPlugin)
+            .add_plugins(VictoryPlugin)
             // UI
             .add_plugins(SetupEguiCamera)
             .add_plugins(MainMenu)
@@ -35,7 +59,8 @@ impl Plugin for GameSystems {
             .add_plugins(BuildingsUI)
             .add_plugins(ArmyRendering)
             .add_plugins(EndTurnUI)
-            .add_plugins(EventUIPlugin);
+            .add_plugins(EventUIPlugin)
+            .add_plugins(GameOverUI);
     }
 }
 
@@ -58,6 +83,26 @@ fn clear_cameras(
     clear_entities(&mut commands, &query3d);
 }
 
+/// Tears down the finished game so the main menu can start a fresh one.
+/// Countries are removed on entering the main menu, see country_selection_ui.rs.
+fn clear_game_world(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        Or<(
+            With<GameWorldEntity>,
+            With<Province>,
+            With<Army>,
+            With<Player>,
+        )>,
+    >,
+    mut clear_color: ResMut<ClearColor>,
+) {
+    clear_entities(&mut commands, &query);
+    commands.remove_resource::<LocalPlayer>();
+    clear_color.0 = Color::srgb_u8(30, 30, 30);
+}
+
 // fn clear_all(mut commands: Commands, query: Query<Entity>){
 //
 //     clear_entities(&mut commands, &query);
@@ -0,0 +1,385 @@
+// plugins/ledger.rs
+use crate::components::army::Army;
+use crate::components::buildings::Buildings;
+use crate::components::country::{Country, DEFAULT_TAX_RATE, VassalOf};
+use crate::components::debt::Debt;
+use crate::components::player::{ControlsCountry, LocalPlayer};
+use crate::components::province::{Occupied, OwnedBy, Province};
+use crate::misc::economy::{army_upkeep, province_economy, tribute};
+use crate::plugins::trade::TradeReport;
+use crate::states::AppState;
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+pub struct LedgerPlugin;
+
+impl Plugin for LedgerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BudgetLedger>().add_systems(
+            EguiPrimaryContextPass,
+            budget_ledger_ui.run_if(in_state(AppState::InGame)),
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExpenseKind {
+    Building,
+    Recruiting,
+    Upkeep,
+    Events,
+    LoanPayments,
+    Reparations,
+    Gifts,
+    Tribute,
+}
+
+impl ExpenseKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExpenseKind::Building => "Buildings",
+            ExpenseKind::Recruiting => "Recruiting",
+            ExpenseKind::Upkeep => "Army upkeep",
+            ExpenseKind::Events => "Events",
+            ExpenseKind::LoanPayments => "Loan payments",
+            ExpenseKind::Reparations => "Reparations",
+            ExpenseKind::Gifts => "Gifts",
+            ExpenseKind::Tribute => "Tribute",
+        }
+    }
+}
+
+pub const ALL_EXPENSES: [ExpenseKind; 8] = [
+    ExpenseKind::Building,
+    ExpenseKind::Recruiting,
+    ExpenseKind::Upkeep,
+    ExpenseKind::Events,
+    ExpenseKind::LoanPayments,
+    ExpenseKind::Reparations,
+    ExpenseKind::Gifts,
+    ExpenseKind::Tribute,
+];
+
+#[derive(Default, Clone, Debug)]
+pub struct TurnBudget {
+    pub province_income: Vec<(u32, u32)>,
+    pub trade_income: u32,
+    pub event_income: u32,
+    pub loot_income: u32,
+    pub reparations_income: u32,
+    pub gift_income: u32,
+    pub tribute_income: u32,
+    pub borrowed: u32,
+    pub expenses: HashMap<ExpenseKind, u32>,
+}
+
+impl TurnBudget {
+    pub fn expense(&self, kind: ExpenseKind) -> u32 {
+        self.expenses.get(&kind).copied().unwrap_or(0)
+    }
+
+    pub fn total_province_income(&self) -> u32 {
+        self.province_income.iter().map(|(_, income)| income).sum()
+    }
+
+    pub fn total_income(&self) -> u32 {
+        self.total_province_income()
+            + self.trade_income
+            + self.event_income
+            + self.loot_income
+            + self.reparations_income
+            + self.gift_income
+            + self.tribute_income
+            + self.borrowed
+    }
+
+    pub fn total_expenses(&self) -> u32 {
+        self.expenses.values().sum()
+    }
+
+    pub fn net(&self) -> i64 {
+        self.total_income() as i64 - self.total_expenses() as i64
+    }
+}
+
+/// Gold flows per country. Everything spent or earned since the last turn was processed
+/// goes to `current`, which becomes `last_turn` when processing ends.
+#[derive(Resource, Default)]
+pub struct BudgetLedger {
+    pub current: HashMap<Entity, TurnBudget>,
+    pub last_turn: HashMap<Entity, TurnBudget>,
+}
+
+impl BudgetLedger {
+    pub fn record_province_income(&mut self, country: Entity, province_id: u32, amount: u32) {
+        self.current
+            .entry(country)
+            .or_default()
+            .province_income
+            .push((province_id, amount));
+    }
+
+    pub fn record_trade_income(&mut self, country: Entity, amount: u32) {
+        self.current.entry(country).or_default().trade_income += amount;
+    }
+
+    pub fn record_event_income(&mut self, country: Entity, amount: u32) {
+        self.current.entry(country).or_default().event_income += amount;
+    }
+
+    pub fn record_loot_income(&mut self, country: Entity, amount: u32) {
+        self.current.entry(country).or_default().loot_income += amount;
+    }
+
+    pub fn record_reparations_income(&mut self, country: Entity, amount: u32) {
+        self.current.entry(country).or_default().reparations_income += amount;
+    }
+
+    pub fn record_gift_income(&mut self, country: Entity, amount: u32) {
+        self.current.entry(country).or_default().gift_income += amount;
+    }
+
+    pub fn record_tribute_income(&mut self, country: Entity, amount: u32) {
+        self.current.entry(country).or_default().tribute_income += amount;
+    }
+
+    pub fn record_borrowing(&mut self, country: Entity, amount: u32) {
+        self.current.entry(country).or_default().borrowed += amount;
+    }
+
+    pub fn record_expense(&mut self, country: Entity, kind: ExpenseKind, amount: u32) {
+        if amount == 0 {
+            return;
+        }
+
+        *self
+            .current
+            .entry(country)
+            .or_default()
+            .expenses
+            .entry(kind)
+            .or_insert(0) += amount;
+    }
+
+    pub fn close_turn(&mut self) {
+        self.last_turn = std::mem::take(&mut self.current);
+        for budget in self.last_turn.values_mut() {
+            budget.province_income.sort();
+        }
+    }
+}
+
+#[derive(SystemParam)]
+struct LedgerQueries<'w, 's> {
+    provinces: Query<
+        'w,
+        's,
+        (
+            &'static Province,
+            &'static OwnedBy,
+            &'static Buildings,
+            Option<&'static Occupied>,
+        ),
+    >,
+    countries: Query<'w, 's, &'static Country>,
+    armies: Query<'w, 's, &'static Army>,
+    debts: Query<'w, 's, &'static Debt>,
+    vassals: Query<'w, 's, (Entity, &'static VassalOf)>,
+}
+
+/// Province income `country` collects next turn, by province id.
+fn forecast_province_income(country: Entity, queries: &LedgerQueries) -> Vec<(u32, u32)> {
+    let mut income = Vec::new();
+
+    for (province, owned_by, buildings, occupied_opt) in &queries.provinces {
+        let collector = occupied_opt.map_or(owned_by.owner, |occ| occ.occupier);
+        if collector != country {
+            continue;
+        }
+
+        let tax_rate = |entity: Entity| {
+            queries
+                .countries
+                .get(entity)
+                .map_or(DEFAULT_TAX_RATE, |c| c.tax_rate)
+        };
+
+        let economy = province_economy(
+            province,
+            buildings,
+            tax_rate(owned_by.owner),
+            occupied_opt.map(|occ| tax_rate(occ.occupier)),
+            queries
+                .countries
+                .get(owned_by.owner)
+                .ok()
+                .and_then(|c| c.culture),
+        );
+
+        income.push((province.id, economy.income));
+    }
+    income.sort();
+
+    income
+}
+
+/// Projects next turn's budget for `country` with the calculation `process_economy` uses.
+fn forecast_budget(country: Entity, queries: &LedgerQueries, trade: &TradeReport) -> TurnBudget {
+    let mut forecast = TurnBudget {
+        province_income: forecast_province_income(country, queries),
+        ..default()
+    };
+
+    if queries.vassals.contains(country) {
+        forecast.expenses.insert(
+            ExpenseKind::Tribute,
+            tribute(forecast.total_province_income()),
+        );
+    }
+
+    forecast.tribute_income = queries
+        .vassals
+        .iter()
+        .filter(|(_, vassal_of)| vassal_of.overlord == country)
+        .map(|(vassal, _)| {
+            let income: u32 = forecast_province_income(vassal, queries)
+                .iter()
+                .map(|(_, income)| income)
+                .sum();
+            tribute(income)
+        })
+        .sum();
+
+    forecast.trade_income = trade
+        .countries
+        .get(&country)
+        .map_or(0, |t| t.total_income());
+
+    let units: u32 = queries
+        .armies
+        .iter()
+        .filter(|army| army.owner == country)
+        .map(|army| army.units)
+        .sum();
+    forecast
+        .expenses
+        .insert(ExpenseKind::Upkeep, army_upkeep(units));
+
+    if let Ok(debt) = queries.debts.get(country) {
+        forecast
+            .expenses
+            .insert(ExpenseKind::LoanPayments, debt.payment_due());
+    }
+
+    forecast
+}
+
+fn budget_ledger_ui(
+    mut contexts: EguiContexts,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+    ledger: Res<BudgetLedger>,
+    trade: Res<TradeReport>,
+    queries: LedgerQueries,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let Some(player_country) = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0)
+    else {
+        return;
+    };
+
+    let last_turn = ledger
+        .last_turn
+        .get(&player_country)
+        .cloned()
+        .unwrap_or_default();
+    let forecast = forecast_budget(player_country, &queries, &trade);
+
+    egui::Window::new("Budget")
+        .anchor(egui::Align2::LEFT_TOP, [10.0, 130.0])
+        .resizable(false)
+        .default_open(false)
+        .show(ctx, |ui| {
+            ui.heading("Last turn");
+            show_budget(ui, "last_turn", &last_turn);
+
+            ui.separator();
+
+            ui.heading("Forecast for next turn");
+            show_budget(ui, "forecast", &forecast);
+        });
+}
+
+fn show_budget(ui: &mut egui::Ui, id: &str, budget: &TurnBudget) {
+    egui::CollapsingHeader::new(format!(
+        "Province income: {}",
+        budget.total_province_income()
+    ))
+    .id_salt(id)
+    .show(ui, |ui| {
+        if budget.province_income.is_empty() {
+            ui.label("No provinces");
+        }
+        for (province_id, income) in &budget.province_income {
+            ui.label(format!("Province {}: {} gold", province_id, income));
+        }
+    });
+
+    ui.label(format!("Trade: {}", budget.trade_income));
+    if budget.event_income > 0 {
+        ui.label(format!("Events: {}", budget.event_income));
+    }
+    if budget.loot_income > 0 {
+        ui.label(format!("Looting: {}", budget.loot_income));
+    }
+    if budget.reparations_income > 0 {
+        ui.label(format!("Reparations: {}", budget.reparations_income));
+    }
+    if budget.gift_income > 0 {
+        ui.label(format!("Gifts received: {}", budget.gift_income));
+    }
+    if budget.tribute_income > 0 {
+        ui.label(format!("Tribute from vassals: {}", budget.tribute_income));
+    }
+    if budget.borrowed > 0 {
+        ui.label(format!("Loans taken: {}", budget.borrowed));
+    }
+    ui.label(
+        egui::RichText::new(format!("Total income: {}", budget.total_income()))
+            .color(egui::Color32::LIGHT_GREEN),
+    );
+
+    ui.add_space(4.0);
+
+    for kind in ALL_EXPENSES {
+        let amount = budget.expense(kind);
+        if amount > 0 {
+            ui.label(format!("{}: -{}", kind.name(), amount));
+        }
+    }
+    ui.label(
+        egui::RichText::new(format!("Total expenses: {}", budget.total_expenses()))
+            .color(egui::Color32::LIGHT_RED),
+    );
+
+    ui.add_space(4.0);
+
+    let net = budget.net();
+    let net_color = if net >= 0 {
+        egui::Color32::GOLD
+    } else {
+        egui::Color32::RED
+    };
+    ui.label(
+        egui::RichText::new(format!("Net change: {:+}", net))
+            .strong()
+            .color(net_color),
+    );
+}
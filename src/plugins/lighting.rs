@@ -3,6 +3,7 @@ use bevy::{
     prelude::*,
 };
 
+use crate::components::game_world_entity::GameWorldEntity;
 use crate::plugins::map_generation::MapGenerated;
 use crate::resources::MapSize;
 use crate::states::AppState;
@@ -38,6 +39,7 @@ fn setup_lighting(mut commands: Commands, map_size: Res<MapSize>) {
             overlap_proportion: 0.3,
         }
         .build(),
+        GameWorldEntity,
     ));
 
     commands.insert_resource(AmbientLight {
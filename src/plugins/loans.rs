@@ -0,0 +1,145 @@
+// plugins/loans.rs
+use crate::components::army::Army;
+use crate::components::country::{AIControlled, Country};
+use crate::components::debt::{Debt, LOAN_AMOUNT};
+use crate::components::events::{GameEvent, PendingEvent};
+use crate::components::player::{ControlsCountry, LocalPlayer};
+use crate::plugins::ledger::{BudgetLedger, ExpenseKind};
+use crate::plugins::turn::TurnResolutionSet;
+use crate::states::{AppState, GamePhase};
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+pub struct LoansPlugin;
+
+impl Plugin for LoansPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            process_loan_payments.in_set(TurnResolutionSet::Debt),
+        )
+        .add_systems(
+            EguiPrimaryContextPass,
+            loans_ui.run_if(in_state(AppState::InGame)),
+        );
+    }
+}
+
+fn process_loan_payments(
+    mut commands: Commands,
+    mut countries: Query<(Entity, &mut Country, &mut Debt, Has<AIControlled>)>,
+    mut armies: Query<&mut Army>,
+    mut ledger: ResMut<BudgetLedger>,
+    pending_event: Option<Res<PendingEvent>>,
+    mut next_state: ResMut<NextState<GamePhase>>,
+) {
+    for (country_entity, mut country, mut debt, is_ai) in &mut countries {
+        if debt.loans.is_empty() {
+            continue;
+        }
+
+        let outcome = debt.pay_installments(&mut country.gold);
+        ledger.record_expense(country_entity, ExpenseKind::LoanPayments, outcome.paid);
+
+        if outcome.missed == 0 {
+            continue;
+        }
+
+        println!(
+            "{} missed {} loan payment(s), now owes {} gold",
+            country.name,
+            outcome.missed,
+            debt.total_owed()
+        );
+
+        if is_ai {
+            for mut army in armies.iter_mut() {
+                if army.owner == country_entity {
+                    army.units -= army.units / 10;
+                }
+            }
+        } else if pending_event.is_none() {
+            commands.insert_resource(PendingEvent::new(GameEvent::debt_crisis()));
+            next_state.set(GamePhase::Event);
+        }
+    }
+}
+
+fn loans_ui(
+    mut contexts: EguiContexts,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+    mut countries: Query<(&mut Country, &mut Debt)>,
+    mut ledger: ResMut<BudgetLedger>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let Some(player_country) = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0)
+    else {
+        return;
+    };
+
+    let Ok((mut country, mut debt)) = countries.get_mut(player_country) else {
+        return;
+    };
+
+    egui::Window::new("Loans")
+        .anchor(egui::Align2::LEFT_TOP, [10.0, 165.0])
+        .resizable(false)
+        .default_open(false)
+        .show(ctx, |ui| {
+            if debt.loans.is_empty() {
+                ui.label("No outstanding loans");
+            }
+
+            for loan in &debt.loans {
+                ui.label(format!(
+                    "{} gold at {:.0}%: {} owed, {} per turn",
+                    loan.principal,
+                    loan.interest_rate * 100.0,
+                    loan.remaining,
+                    loan.next_payment()
+                ));
+            }
+
+            if !debt.loans.is_empty() {
+                ui.separator();
+                ui.label(format!(
+                    "Total owed: {} gold, due next turn: {} gold",
+                    debt.total_owed(),
+                    debt.payment_due()
+                ));
+            }
+
+            if debt.missed_payments > 0 {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    format!(
+                        "Missed payments: {}. No new credit until the debt is repaid.",
+                        debt.missed_payments
+                    ),
+                );
+            }
+
+            ui.add_space(8.0);
+
+            let button_text = format!(
+                "Borrow {} gold at {:.0}% interest",
+                LOAN_AMOUNT,
+                debt.interest_rate() * 100.0
+            );
+
+            if ui
+                .add_enabled(debt.can_borrow(), egui::Button::new(button_text))
+                .clicked()
+            {
+                debt.take_loan(LOAN_AMOUNT);
+                country.gold += LOAN_AMOUNT;
+                ledger.record_borrowing(player_country, LOAN_AMOUNT);
+            }
+        });
+}
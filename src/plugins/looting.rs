@@ -0,0 +1,267 @@
+// plugins/looting.rs
+use crate::components::army::{Army, LootOrder};
+use crate::components::buildings::{BuildingType, Buildings};
+use crate::components::country::{AIControlled, Country, Grievances};
+use crate::components::player::{ControlsCountry, LocalPlayer};
+use crate::components::province::{Occupied, OwnedBy, Province};
+use crate::misc::CommandsAndContexts;
+use crate::plugins::ledger::BudgetLedger;
+use crate::plugins::selection::{CurrentSelection, SelectedEntity};
+use crate::plugins::turn::TurnResolutionSet;
+use crate::states::{AppState, GamePhase};
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+use rand::Rng;
+
+/// Share of a province's population lost each time it is looted.
+const LOOT_POPULATION_SHARE: f32 = 0.1;
+/// Grievance the victim holds against the looter for every looting.
+const LOOT_GRIEVANCE: f32 = 10.0;
+const GRIEVANCE_FADE: f32 = 1.0;
+const MAX_LOOT_LOG: usize = 20;
+
+pub struct LootingPlugin;
+
+impl Plugin for LootingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LootLog>()
+            .add_systems(
+                Update,
+                ai_order_looting.in_set(TurnResolutionSet::AIDecision),
+            )
+            .add_systems(
+                Update,
+                resolve_looting.in_set(TurnResolutionSet::Occupation),
+            )
+            .add_systems(Update, fade_grievances.in_set(TurnResolutionSet::End))
+            .add_systems(
+                EguiPrimaryContextPass,
+                (army_orders_ui, loot_log_ui).run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LootRecord {
+    pub looter: Entity,
+    pub victim: Entity,
+    pub province_id: u32,
+    pub gold: u32,
+    pub population: u32,
+    pub destroyed: Option<BuildingType>,
+}
+
+/// The most recent lootings, newest last.
+#[derive(Resource, Default)]
+pub struct LootLog {
+    pub entries: Vec<LootRecord>,
+}
+
+impl LootLog {
+    fn push(&mut self, record: LootRecord) {
+        self.entries.push(record);
+        if self.entries.len() > MAX_LOOT_LOG {
+            self.entries.remove(0);
+        }
+    }
+}
+
+fn ai_order_looting(
+    mut commands: Commands,
+    armies: Query<(Entity, &Army)>,
+    ai_countries: Query<&Country, With<AIControlled>>,
+    occupied: Query<&Occupied>,
+) {
+    let mut rng = rand::rng();
+
+    for (army_entity, army) in &armies {
+        let Ok(country) = ai_countries.get(army.owner) else {
+            continue;
+        };
+
+        let holds_province = occupied
+            .get(army.province)
+            .is_ok_and(|occ| occ.occupier == army.owner);
+
+        // Poor countries always plunder; rich ones only now and then.
+        if holds_province && (country.gold < 1000 || rng.random_bool(0.25)) {
+            commands.entity(army_entity).insert(LootOrder);
+        }
+    }
+}
+
+fn resolve_looting(
+    mut commands: Commands,
+    looting_armies: Query<(Entity, &Army), With<LootOrder>>,
+    mut provinces: Query<(&mut Province, &OwnedBy, &Occupied, &mut Buildings)>,
+    mut countries: Query<(&mut Country, &mut Grievances)>,
+    mut ledger: ResMut<BudgetLedger>,
+    mut log: ResMut<LootLog>,
+) {
+    let mut looted = HashSet::new();
+
+    for (army_entity, army) in &looting_armies {
+        commands.entity(army_entity).remove::<LootOrder>();
+
+        let Ok((mut province, owned_by, occupied, mut buildings)) =
+            provinces.get_mut(army.province)
+        else {
+            continue;
+        };
+
+        // Only land the army already holds can be looted, and only once a turn.
+        if occupied.occupier != army.owner || !looted.insert(army.province) {
+            continue;
+        }
+
+        let population = (province.population as f32 * LOOT_POPULATION_SHARE) as u32;
+        province.population -= population;
+
+        let destroyed = buildings.built.pop();
+        let gold = population.min(army.units) + destroyed.map_or(0, |b| b.cost() / 2);
+
+        let mut looter_name = String::new();
+        if let Ok((mut looter, _)) = countries.get_mut(army.owner) {
+            looter.gold += gold;
+            ledger.record_loot_income(army.owner, gold);
+            looter_name = looter.name.clone();
+        }
+
+        if let Ok((_, mut grievances)) = countries.get_mut(owned_by.owner) {
+            grievances.add(army.owner, LOOT_GRIEVANCE);
+        }
+
+        println!(
+            "Province {} looted by {}: {} gold, {} people lost{}",
+            province.id,
+            looter_name,
+            gold,
+            population,
+            destroyed.map_or(String::new(), |b| format!(", {} destroyed", b.name()))
+        );
+
+        log.push(LootRecord {
+            looter: army.owner,
+            victim: owned_by.owner,
+            province_id: province.id,
+            gold,
+            population,
+            destroyed,
+        });
+    }
+}
+
+fn fade_grievances(mut countries: Query<&mut Grievances>) {
+    for mut grievances in &mut countries {
+        grievances.fade(GRIEVANCE_FADE);
+    }
+}
+
+fn army_orders_ui(
+    commands_and_contexts: CommandsAndContexts,
+    selection: Res<CurrentSelection>,
+    game_phase: Res<State<GamePhase>>,
+    armies: Query<(&Army, Has<LootOrder>)>,
+    provinces: Query<(&Province, Option<&Occupied>)>,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+) {
+    let mut commands = commands_and_contexts.commands;
+    let mut contexts = commands_and_contexts.contexts;
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let Some(SelectedEntity::Army(army_entity)) = selection.entity else {
+        return;
+    };
+
+    let Ok((army, has_loot_order)) = armies.get(army_entity) else {
+        return;
+    };
+
+    let player_country = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0);
+
+    if Some(army.owner) != player_country {
+        return;
+    }
+
+    let Ok((province, occupied_opt)) = provinces.get(army.province) else {
+        return;
+    };
+
+    egui::Window::new("Army")
+        .resizable(false)
+        .anchor(egui::Align2::LEFT_BOTTOM, [280.0, -10.0])
+        .pivot(egui::Align2::LEFT_BOTTOM)
+        .show(ctx, |ui| {
+            ui.label(format!("Units: {}", army.units));
+            ui.label(format!("Province: {}", province.id));
+
+            let can_loot = occupied_opt.is_some_and(|occ| occ.occupier == army.owner)
+                && *game_phase.get() == GamePhase::PlayerTurn;
+
+            let mut loot = has_loot_order;
+            let checkbox = ui.add_enabled(
+                can_loot,
+                egui::Checkbox::new(&mut loot, "Loot this province at end of turn"),
+            );
+
+            if checkbox.changed() {
+                if loot {
+                    commands.entity(army_entity).insert(LootOrder);
+                } else {
+                    commands.entity(army_entity).remove::<LootOrder>();
+                }
+            }
+
+            if !can_loot {
+                ui.label(
+                    egui::RichText::new("Only provinces this army occupies can be looted")
+                        .small()
+                        .color(egui::Color32::GRAY),
+                );
+            }
+        });
+}
+
+fn loot_log_ui(mut contexts: EguiContexts, log: Res<LootLog>, countries: Query<&Country>) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let name = |entity: Entity| {
+        countries
+            .get(entity)
+            .map_or("Unknown".to_string(), |c| c.name.clone())
+    };
+
+    egui::Window::new("Looting")
+        .anchor(egui::Align2::LEFT_TOP, [10.0, 200.0])
+        .resizable(false)
+        .default_open(false)
+        .show(ctx, |ui| {
+            if log.entries.is_empty() {
+                ui.label("No province has been looted");
+            }
+
+            for record in log.entries.iter().rev() {
+                let mut text = format!(
+                    "{} looted province {} of {}: {} gold, {} people",
+                    name(record.looter),
+                    record.province_id,
+                    name(record.victim),
+                    record.gold,
+                    record.population
+                );
+                if let Some(building) = record.destroyed {
+                    text.push_str(&format!(", {} destroyed", building.name()));
+                }
+                ui.label(text);
+            }
+        });
+}
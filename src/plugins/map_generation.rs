@@ -1,7 +1,9 @@
 // plugins/map_generation.rs
 use crate::components::buildings::Buildings;
+use crate::components::game_world_entity::GameWorldEntity;
 use crate::components::province::*;
-use crate::resources::MapSize;
+use crate::components::trade::TradeGoodDef;
+use crate::resources::{MapSize, TradePrices};
 use crate::states::AppState;
 use anyhow::Context;
 use bevy::platform::collections::{HashMap, HashSet};
@@ -45,6 +47,7 @@ fn load_map_geometry(
     let map_size = Vec2::from(map_data.map_size);
 
     commands.insert_resource(MapSize(map_size));
+    commands.insert_resource(TradePrices::from_defs(&map_data.trade_goods));
 
     let provinces = generate_provinces(&map_data.provinces, map_size);
     let province_meshes = provinces_to_meshes(&provinces);
@@ -139,6 +142,10 @@ fn generate_provinces(province_defs: &[ProvinceDef], map_size: Vec2) -> Vec<Prov
             population: prov_def.population,
             base_growth: prov_def.base_growth,
             base_income: prov_def.base_income,
+            trade_good: prov_def.trade_good,
+            culture: prov_def.culture,
+            religion: prov_def.religion,
+            unrest: 0.0,
         })
         .collect()
 }
@@ -308,6 +315,7 @@ fn add_background_mesh(
             rotation: Quat::from_rotation_x(90f32.to_radians()),
             scale: Vec3::ONE * scale,
         },
+        GameWorldEntity,
     ));
 }
 
@@ -319,5 +327,7 @@ fn load_map_data_from_file() -> anyhow::Result<MapData> {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MapData {
     pub map_size: (f32, f32),
+    #[serde(default)]
+    pub trade_goods: Vec<TradeGoodDef>,
     pub provinces: Vec<ProvinceDef>,
 }
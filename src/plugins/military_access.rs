@@ -0,0 +1,191 @@
+// plugins/military_access.rs
+use crate::components::country::{
+    AIControlled, Country, DiplomacyChanged, RebelFaction, Relation, Relations,
+};
+use crate::components::opinion::Opinions;
+use crate::plugins::proposals::{ProposalAnswered, ProposalKind, Proposals};
+use crate::plugins::turn::TurnResolutionSet;
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use rand::Rng;
+use rand::prelude::IndexedRandom;
+
+/// Countries grant access to those they think at least this well of.
+const GRANT_ACCESS_OPINION: f32 = 0.0;
+/// AI countries revoke access from those they think less of than this.
+const REVOKE_ACCESS_OPINION: f32 = -25.0;
+
+pub struct MilitaryAccessPlugin;
+
+impl Plugin for MilitaryAccessPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MilitaryAccess>()
+            .add_systems(
+                Update,
+                (ai_answer_access_proposals, ai_military_access)
+                    .chain()
+                    .in_set(TurnResolutionSet::AIDecision),
+            )
+            .add_observer(on_war_end_access)
+            .add_observer(on_access_proposal_answered);
+    }
+}
+
+/// Access agreements as (granter, grantee) pairs: the grantee's armies may
+/// move through the granter's land.
+#[derive(Resource, Default, Debug)]
+pub struct MilitaryAccess {
+    pub grants: HashSet<(Entity, Entity)>,
+}
+
+impl MilitaryAccess {
+    pub fn has(&self, granter: Entity, grantee: Entity) -> bool {
+        self.grants.contains(&(granter, grantee))
+    }
+
+    pub fn grant(&mut self, granter: Entity, grantee: Entity) {
+        self.grants.insert((granter, grantee));
+    }
+
+    pub fn revoke(&mut self, granter: Entity, grantee: Entity) {
+        self.grants.remove(&(granter, grantee));
+    }
+}
+
+/// Whether armies of `mover` may enter land owned by `owner`.
+pub fn may_enter(
+    relations: &Relations,
+    access: &MilitaryAccess,
+    mover: Entity,
+    owner: Entity,
+) -> bool {
+    mover == owner
+        || matches!(relations.get(owner), Relation::War | Relation::Alliance)
+        || access.has(owner, mover)
+}
+
+/// Whether a country with `relations` and `opinions` lets `requester` through.
+/// Allies always do; others only if they think well enough of the requester.
+pub fn grants_access(relations: &Relations, opinions: &Opinions, requester: Entity) -> bool {
+    match relations.get(requester) {
+        Relation::Alliance => true,
+        Relation::War => false,
+        Relation::Peace => opinions.get(requester) >= GRANT_ACCESS_OPINION,
+    }
+}
+
+/// Access between two countries ends when they go to war.
+fn on_war_end_access(trigger: On<DiplomacyChanged>, mut access: ResMut<MilitaryAccess>) {
+    let ev = trigger.event();
+
+    if ev.new_relation == Relation::War {
+        access.revoke(ev.declarer, ev.target);
+        access.revoke(ev.target, ev.declarer);
+    }
+}
+
+/// Grants access once the receiver of a request accepts it.
+fn on_access_proposal_answered(
+    trigger: On<ProposalAnswered>,
+    mut access: ResMut<MilitaryAccess>,
+    relations: Query<&Relations>,
+) {
+    let ev = trigger.event();
+    let proposal = &ev.proposal;
+
+    if !ev.accepted || !matches!(proposal.kind, ProposalKind::MilitaryAccess) {
+        return;
+    }
+
+    let at_war = relations
+        .get(proposal.to)
+        .is_ok_and(|relations| relations.get(proposal.from) == Relation::War);
+    if !at_war {
+        access.grant(proposal.to, proposal.from);
+    }
+}
+
+fn ai_answer_access_proposals(
+    mut commands: Commands,
+    mut proposals: ResMut<Proposals>,
+    ai_countries: Query<(&Relations, &Opinions), With<AIControlled>>,
+) {
+    let requests = proposals.take(|proposal| {
+        matches!(proposal.kind, ProposalKind::MilitaryAccess) && ai_countries.contains(proposal.to)
+    });
+
+    for proposal in requests {
+        let accepted = ai_countries
+            .get(proposal.to)
+            .is_ok_and(|(relations, opinions)| grants_access(relations, opinions, proposal.from));
+        commands.trigger(ProposalAnswered { proposal, accepted });
+    }
+}
+
+fn ai_military_access(
+    ai_countries: Query<(Entity, &Relations, &Opinions), With<AIControlled>>,
+    countries: Query<
+        (Entity, &Country, &Relations, &Opinions, Has<AIControlled>),
+        Without<RebelFaction>,
+    >,
+    rebels: Query<Entity, With<RebelFaction>>,
+    mut access: ResMut<MilitaryAccess>,
+    mut proposals: ResMut<Proposals>,
+) {
+    let mut rng = rand::rng();
+
+    // Grants to countries that have fallen out of favour are revoked.
+    access.grants.retain(|&(granter, grantee)| {
+        ai_countries.get(granter).map_or(true, |(_, _, opinions)| {
+            opinions.get(grantee) >= REVOKE_ACCESS_OPINION
+        })
+    });
+
+    for (country_entity, relations, _) in &ai_countries {
+        let at_war = relations
+            .relations
+            .iter()
+            .any(|(&other, &relation)| relation == Relation::War && !rebels.contains(other));
+
+        // Only countries with a war to fight go looking for a way through.
+        if !at_war || !rng.random_bool(0.1) {
+            continue;
+        }
+
+        let candidates: Vec<Entity> = countries
+            .iter()
+            .filter(|&(other, ..)| {
+                other != country_entity
+                    && relations.get(other) == Relation::Peace
+                    && !access.has(other, country_entity)
+            })
+            .map(|(other, ..)| other)
+            .collect();
+
+        let Some(&granter) = candidates.choose(&mut rng) else {
+            continue;
+        };
+
+        let Ok((_, granter_country, granter_relations, granter_opinions, granter_is_ai)) =
+            countries.get(granter)
+        else {
+            continue;
+        };
+
+        // The player answers from the diplomatic inbox.
+        if !granter_is_ai {
+            proposals.send(country_entity, granter, ProposalKind::MilitaryAccess);
+            continue;
+        }
+
+        if grants_access(granter_relations, granter_opinions, country_entity) {
+            access.grant(granter, country_entity);
+            if let Ok((_, country, ..)) = countries.get(country_entity) {
+                println!(
+                    "{} granted military access to {}",
+                    granter_country.name, country.name
+                );
+            }
+        }
+    }
+}
@@ -1,43 +1,77 @@
+mod alliances;
 mod army_movement;
 mod army_systems;
 mod army_visuals;
 mod buildings_ui;
 mod camera_controls;
+mod claims;
+mod coalitions;
 mod country_selection_ui;
 mod diplomacy;
 mod end_turn_ui;
 mod events_ui;
+mod game_over_ui;
 mod game_systems;
+mod ledger;
 mod lighting;
+mod loans;
+mod looting;
 mod main_menu_ui;
 mod map_generation;
+mod military_access;
+mod opinion;
+mod peace;
 mod player_country_ui;
+mod proposals;
 mod province_info_ui;
 mod province_visuals;
 mod save_load;
 mod selection;
 mod setup_egui_camera;
 mod terrain_visual_3d;
+mod trade;
+mod truces;
 mod turn;
+mod unrest;
+mod vassals;
+mod victory;
+mod war_score;
 
+pub use alliances::AlliancesPlugin;
 pub use army_movement::ArmyMovementPlugin;
 pub use army_systems::ArmySystemsPlugin;
 pub use army_visuals::ArmyRendering;
 pub use buildings_ui::BuildingsUI;
 pub use camera_controls::GameCamera;
+pub use claims::ClaimsPlugin;
+pub use coalitions::CoalitionsPlugin;
 pub use country_selection_ui::CountrySelectionUI;
 pub use diplomacy::DiplomacyPlugin;
 pub use end_turn_ui::EndTurnUI;
 pub use events_ui::EventUIPlugin;
+pub use game_over_ui::GameOverUI;
 pub use game_systems::GameSystems;
+pub use ledger::LedgerPlugin;
 pub use lighting::Lighting;
+pub use loans::LoansPlugin;
+pub use looting::LootingPlugin;
 pub use main_menu_ui::MainMenu;
 pub use map_generation::MapGenerationPlugin;
+pub use military_access::MilitaryAccessPlugin;
+pub use opinion::OpinionPlugin;
+pub use peace::PeacePlugin;
 pub use player_country_ui::PlayerCountryUI;
+pub use proposals::ProposalsPlugin;
 pub use province_info_ui::ProvinceInfoUI;
 pub use province_visuals::ProvinceVisualsPlugin;
 pub use save_load::SaveLoadPlugin;
 pub use selection::SelectionPlugin;
 pub use setup_egui_camera::SetupEguiCamera;
 pub use terrain_visual_3d::Terrain3DVisualsPlugin;
+pub use trade::TradePlugin;
+pub use truces::TrucesPlugin;
 pub use turn::TurnPlugin;
+pub use unrest::UnrestPlugin;
+pub use vassals::VassalsPlugin;
+pub use victory::VictoryPlugin;
+pub use war_score::WarScorePlugin;
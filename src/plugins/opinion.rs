@@ -0,0 +1,139 @@
+// plugins/opinion.rs
+use crate::components::country::{
+    Country, DiplomacyChanged, Grievances, RebelFaction, Relation, Relations,
+};
+use crate::components::opinion::{OpinionModifierKind, Opinions};
+use crate::components::province::{Occupied, OwnedBy, Province};
+use crate::plugins::turn::TurnResolutionSet;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+
+const DECLARED_WAR_OPINION: f32 = -50.0;
+const OCCUPIED_PROVINCE_OPINION: f32 = -5.0;
+const SHARED_ENEMY_OPINION: f32 = 25.0;
+const BORDER_OPINION: f32 = -10.0;
+const OPINION_DECAY: f32 = 2.0;
+
+/// Gold sent with a diplomatic gift and the opinion it buys.
+pub const GIFT_GOLD: u32 = 100;
+pub const GIFT_OPINION: f32 = 20.0;
+
+pub struct OpinionPlugin;
+
+impl Plugin for OpinionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, update_opinions.in_set(TurnResolutionSet::End))
+            .add_observer(on_war_declared_opinion);
+    }
+}
+
+fn on_war_declared_opinion(trigger: On<DiplomacyChanged>, mut opinions: Query<&mut Opinions>) {
+    let ev = trigger.event();
+
+    if ev.new_relation != Relation::War {
+        return;
+    }
+
+    if let Ok(mut target_opinions) = opinions.get_mut(ev.target) {
+        target_opinions.add(
+            ev.declarer,
+            OpinionModifierKind::DeclaredWar,
+            DECLARED_WAR_OPINION,
+        );
+    }
+}
+
+/// Fades the modifiers left by past deeds and recalculates the ones that
+/// follow from the map and the wars being fought.
+fn update_opinions(
+    mut countries: Query<(Entity, &Relations, &Grievances, &mut Opinions), With<Country>>,
+    rebels: Query<Entity, With<RebelFaction>>,
+    provinces: Query<(&Province, &OwnedBy, Option<&Occupied>)>,
+) {
+    let owners: HashMap<u32, Entity> = provinces
+        .iter()
+        .map(|(province, owned_by, _)| (province.id, owned_by.owner))
+        .collect();
+
+    let mut borders: HashSet<(Entity, Entity)> = HashSet::new();
+    let mut occupations: HashMap<(Entity, Entity), u32> = HashMap::new();
+
+    for (province, owned_by, occupied) in &provinces {
+        for neighbor in &province.neighbors {
+            if let Some(&other) = owners.get(neighbor)
+                && other != owned_by.owner
+            {
+                borders.insert((owned_by.owner, other));
+            }
+        }
+
+        if let Some(occupied) = occupied {
+            *occupations
+                .entry((owned_by.owner, occupied.occupier))
+                .or_insert(0) += 1;
+        }
+    }
+
+    // Everyone fights the rebels, so they do not make anyone a shared enemy.
+    let enemies: HashMap<Entity, HashSet<Entity>> = countries
+        .iter()
+        .filter(|(entity, ..)| !rebels.contains(*entity))
+        .map(|(entity, relations, ..)| {
+            let at_war = relations
+                .relations
+                .iter()
+                .filter(|&(&other, &relation)| relation == Relation::War && !rebels.contains(other))
+                .map(|(&other, _)| other)
+                .collect();
+            (entity, at_war)
+        })
+        .collect();
+
+    for (country, _, grievances, mut opinions) in &mut countries {
+        opinions.decay(OPINION_DECAY);
+
+        let Some(our_enemies) = enemies.get(&country) else {
+            continue;
+        };
+
+        for (&other, their_enemies) in &enemies {
+            if other == country {
+                continue;
+            }
+
+            let shared_enemy = our_enemies.intersection(their_enemies).next().is_some();
+            opinions.set(
+                other,
+                OpinionModifierKind::SharedEnemy,
+                if shared_enemy {
+                    SHARED_ENEMY_OPINION
+                } else {
+                    0.0
+                },
+            );
+
+            opinions.set(
+                other,
+                OpinionModifierKind::Borders,
+                if borders.contains(&(country, other)) {
+                    BORDER_OPINION
+                } else {
+                    0.0
+                },
+            );
+
+            let occupied = occupations.get(&(country, other)).copied().unwrap_or(0);
+            opinions.set(
+                other,
+                OpinionModifierKind::OccupiesOurLand,
+                occupied as f32 * OCCUPIED_PROVINCE_OPINION,
+            );
+
+            opinions.set(
+                other,
+                OpinionModifierKind::Grievances,
+                -grievances.get(other),
+            );
+        }
+    }
+}
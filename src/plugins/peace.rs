@@ -0,0 +1,651 @@
+// plugins/peace.rs
+use crate::components::country::{
+    AIControlled, Country, DiplomacyChanged, RebelFaction, Relation, Relations, VassalOf,
+};
+use crate::components::opinion::Opinions;
+use crate::components::personality::Personality;
+use crate::components::player::{ControlsCountry, LocalPlayer};
+use crate::components::province::{Core, Occupied, OwnedBy, Province};
+use crate::misc::CommandsAndContexts;
+use crate::plugins::claims::{Claims, claims_province};
+use crate::plugins::coalitions::ProvinceConquered;
+use crate::plugins::ledger::{BudgetLedger, ExpenseKind};
+use crate::plugins::proposals::{ProposalAnswered, ProposalKind, Proposals};
+use crate::plugins::turn::TurnResolutionSet;
+use crate::plugins::vassals::{is_bound, make_vassal};
+use crate::plugins::war_score::Wars;
+use crate::states::AppState;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_egui::{EguiPrimaryContextPass, egui};
+use rand::Rng;
+use rand::prelude::IndexedRandom;
+
+/// Gold worth one point of war score in a deal.
+const GOLD_PER_POINT: u32 = 50;
+/// How much worse than the war warrants a deal may be before a country refuses it.
+const PEACE_WILLINGNESS: i32 = 10;
+/// Opinion worth one point of leverage: liked countries get better terms.
+const OPINION_PER_POINT: f32 = 10.0;
+/// War exhaustion worth one point of leverage: worn-out countries give up more.
+const EXHAUSTION_PER_POINT: f32 = 4.0;
+/// AI countries will not make peace with a country they think this little of.
+const OPINION_BLOCKS_PEACE: f32 = -50.0;
+
+pub struct PeacePlugin;
+
+impl Plugin for PeacePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (ai_answer_peace_proposals, ai_negotiate_peace)
+                .chain()
+                .in_set(TurnResolutionSet::AIDecision),
+        )
+        .add_systems(
+            EguiPrimaryContextPass,
+            peace_negotiation_window.run_if(in_state(AppState::InGame)),
+        )
+        .add_observer(on_peace_proposal_answered);
+    }
+}
+
+/// Terms of a peace proposed by one side of a war to the other.
+/// Demands go from the target to the proposer, offers the other way.
+#[derive(Clone, Debug, Default)]
+pub struct PeaceDeal {
+    pub demanded_provinces: Vec<Entity>,
+    pub offered_provinces: Vec<Entity>,
+    pub demanded_gold: u32,
+    pub offered_gold: u32,
+    pub demand_vassalage: bool,
+}
+
+impl PeaceDeal {
+    pub fn is_white_peace(&self) -> bool {
+        self.demanded_provinces.is_empty()
+            && self.offered_provinces.is_empty()
+            && self.demanded_gold == 0
+            && self.offered_gold == 0
+            && !self.demand_vassalage
+    }
+}
+
+/// The peace the player is negotiating, if the negotiation window is open.
+#[derive(Resource)]
+pub struct PeaceNegotiation {
+    pub target: Entity,
+    pub deal: PeaceDeal,
+}
+
+impl PeaceNegotiation {
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            deal: PeaceDeal::default(),
+        }
+    }
+}
+
+pub fn province_value(province: &Province) -> i32 {
+    10 + (province.population / 250) as i32
+}
+
+/// Everything needed to weigh and carry out a peace deal.
+#[derive(SystemParam)]
+pub struct PeaceTable<'w, 's> {
+    provinces: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Province,
+            &'static mut OwnedBy,
+            Option<&'static Occupied>,
+        ),
+    >,
+    wars: Res<'w, Wars>,
+    countries: Query<'w, 's, (&'static mut Country, &'static mut Relations)>,
+    opinions: Query<'w, 's, &'static Opinions>,
+    personalities: Query<'w, 's, &'static Personality>,
+    vassals: Query<'w, 's, &'static VassalOf>,
+    claims: Res<'w, Claims>,
+    cores: Query<'w, 's, &'static Core>,
+}
+
+impl PeaceTable<'_, '_> {
+    /// Cost of demanding `province`; land the proposer does not hold costs double,
+    /// and land it has a claim on costs half.
+    pub fn demand_cost(&self, proposer: Entity, province: Entity) -> i32 {
+        let Ok((_, province_data, _, occupied)) = self.provinces.get(province) else {
+            return 0;
+        };
+
+        let mut cost = province_value(province_data);
+        if !occupied.is_some_and(|occ| occ.occupier == proposer) {
+            cost *= 2;
+        }
+        if self.is_claimed(proposer, province) {
+            cost /= 2;
+        }
+        cost
+    }
+
+    pub fn is_claimed(&self, claimant: Entity, province: Entity) -> bool {
+        claims_province(
+            &self.claims,
+            claimant,
+            province,
+            self.cores.get(province).ok(),
+        )
+    }
+
+    /// Cost of making `target` a vassal: half of what all its land is worth.
+    pub fn vassalage_cost(&self, target: Entity) -> i32 {
+        let land: i32 = self
+            .provinces
+            .iter()
+            .filter(|(_, _, owned_by, _)| owned_by.owner == target)
+            .map(|(_, province, ..)| province_value(province))
+            .sum();
+        land / 2
+    }
+
+    /// Whether `overlord` may demand that `target` become its vassal.
+    pub fn can_vassalize(&self, overlord: Entity, target: Entity) -> bool {
+        !self.vassals.contains(overlord) && !is_bound(&self.vassals, target)
+    }
+
+    pub fn offer_value(&self, province: Entity) -> i32 {
+        self.provinces
+            .get(province)
+            .map_or(0, |(_, province, ..)| province_value(province))
+    }
+
+    /// What the deal asks of the target, minus what it gives, in war score points.
+    pub fn deal_cost(&self, proposer: Entity, target: Entity, deal: &PeaceDeal) -> i32 {
+        let demanded: i32 = deal
+            .demanded_provinces
+            .iter()
+            .map(|&p| self.demand_cost(proposer, p))
+            .sum();
+        let offered: i32 = deal
+            .offered_provinces
+            .iter()
+            .map(|&p| self.offer_value(p))
+            .sum();
+
+        let vassalage = if deal.demand_vassalage {
+            self.vassalage_cost(target)
+        } else {
+            0
+        };
+
+        demanded - offered + vassalage + (deal.demanded_gold / GOLD_PER_POINT) as i32
+            - (deal.offered_gold / GOLD_PER_POINT) as i32
+    }
+
+    /// How much `target` is willing to give up: the proposer's war score, plus
+    /// goodwill if the target thinks well of them, weariness if it is exhausted,
+    /// and how readily its personality makes peace.
+    pub fn leverage(&self, proposer: Entity, target: Entity) -> i32 {
+        let opinion = self
+            .opinions
+            .get(target)
+            .map_or(0.0, |opinions| opinions.get(proposer));
+
+        let exhaustion = self.wars.exhaustion(target, proposer);
+        let willingness = self
+            .personalities
+            .get(target)
+            .map_or(0, |personality| personality.peace_willingness());
+
+        self.wars.score(proposer, target).total()
+            + PEACE_WILLINGNESS
+            + willingness
+            + (opinion / OPINION_PER_POINT) as i32
+            + (exhaustion / EXHAUSTION_PER_POINT) as i32
+    }
+
+    /// The target accepts when the deal asks no more than the proposer's leverage.
+    pub fn accepts(&self, proposer: Entity, target: Entity, deal: &PeaceDeal) -> bool {
+        self.deal_cost(proposer, target, deal) <= self.leverage(proposer, target)
+    }
+
+    /// The best deal `proposer` can expect `target` to accept.
+    pub fn ai_deal(&self, proposer: Entity, target: Entity) -> PeaceDeal {
+        let mut deal = PeaceDeal::default();
+        let mut budget = self.leverage(proposer, target);
+
+        if budget < 0 {
+            // Losing: buy peace with gold if there is any.
+            let gold = self.countries.get(proposer).map_or(0, |(c, _)| c.gold);
+            deal.offered_gold = ((-budget) as u32 * GOLD_PER_POINT).min(gold);
+            return deal;
+        }
+
+        // A beaten enemy is worth more as a vassal than for a few provinces.
+        let vassalage_cost = self.vassalage_cost(target);
+        if self.can_vassalize(proposer, target) && vassalage_cost <= budget {
+            deal.demand_vassalage = true;
+            budget -= vassalage_cost;
+        }
+
+        let mut held: Vec<(Entity, i32)> = self
+            .provinces
+            .iter()
+            .filter(|&(entity, _, owned_by, occupied)| {
+                owned_by.owner == target
+                    && (occupied.is_some_and(|occ| occ.occupier == proposer)
+                        || self.is_claimed(proposer, entity))
+            })
+            .map(|(entity, ..)| (entity, self.demand_cost(proposer, entity)))
+            .collect();
+        held.sort_by_key(|&(_, cost)| std::cmp::Reverse(cost));
+
+        for (province, cost) in held {
+            if cost <= budget {
+                deal.demanded_provinces.push(province);
+                budget -= cost;
+            }
+        }
+
+        let target_gold = self.countries.get(target).map_or(0, |(c, _)| c.gold);
+        deal.demanded_gold = (budget.max(0) as u32 * GOLD_PER_POINT).min(target_gold);
+
+        deal
+    }
+
+    /// Makes peace on the terms of `deal`.
+    pub fn apply(
+        &mut self,
+        commands: &mut Commands,
+        ledger: &mut BudgetLedger,
+        proposer: Entity,
+        target: Entity,
+        deal: &PeaceDeal,
+    ) {
+        let transfers = deal
+            .demanded_provinces
+            .iter()
+            .map(|&p| (p, target, proposer))
+            .chain(
+                deal.offered_provinces
+                    .iter()
+                    .map(|&p| (p, proposer, target)),
+            );
+
+        for (province, from, to) in transfers {
+            if let Ok((_, _, mut owned_by, _)) = self.provinces.get_mut(province)
+                && owned_by.owner == from
+            {
+                owned_by.owner = to;
+                commands.trigger(ProvinceConquered {
+                    conqueror: to,
+                    province,
+                });
+            }
+        }
+
+        self.pay(ledger, target, proposer, deal.demanded_gold);
+        self.pay(ledger, proposer, target, deal.offered_gold);
+
+        if let Ok((_, mut relations)) = self.countries.get_mut(proposer) {
+            relations.set(target, Relation::Peace);
+        }
+        if let Ok((_, mut relations)) = self.countries.get_mut(target) {
+            relations.set(proposer, Relation::Peace);
+        }
+
+        commands.trigger(DiplomacyChanged {
+            declarer: proposer,
+            target,
+            new_relation: Relation::Peace,
+        });
+
+        if deal.demand_vassalage
+            && self.can_vassalize(proposer, target)
+            && let Ok([(_, mut vassal_relations), (_, mut overlord_relations)]) =
+                self.countries.get_many_mut([target, proposer])
+        {
+            make_vassal(
+                commands,
+                (target, &mut vassal_relations),
+                (proposer, &mut overlord_relations),
+            );
+        }
+    }
+
+    fn pay(&mut self, ledger: &mut BudgetLedger, from: Entity, to: Entity, amount: u32) {
+        let Ok((mut payer, _)) = self.countries.get_mut(from) else {
+            return;
+        };
+        let paid = amount.min(payer.gold);
+        payer.gold -= paid;
+        ledger.record_expense(from, ExpenseKind::Reparations, paid);
+
+        if let Ok((mut payee, _)) = self.countries.get_mut(to) {
+            payee.gold += paid;
+            ledger.record_reparations_income(to, paid);
+        }
+    }
+
+    fn name(&self, country: Entity) -> String {
+        self.countries
+            .get(country)
+            .map_or("Unknown".to_string(), |(c, _)| c.name.clone())
+    }
+
+    fn gold(&self, country: Entity) -> u32 {
+        self.countries.get(country).map_or(0, |(c, _)| c.gold)
+    }
+
+    fn at_war(&self, a: Entity, b: Entity) -> bool {
+        self.countries
+            .get(a)
+            .is_ok_and(|(_, relations)| relations.get(b) == Relation::War)
+    }
+}
+
+/// Makes peace once the receiver of a peace proposal accepts it.
+fn on_peace_proposal_answered(
+    trigger: On<ProposalAnswered>,
+    mut commands: Commands,
+    mut peace: PeaceTable,
+    mut ledger: ResMut<BudgetLedger>,
+) {
+    let ev = trigger.event();
+    let proposal = &ev.proposal;
+
+    let ProposalKind::Peace(deal) = &proposal.kind else {
+        return;
+    };
+
+    if !ev.accepted || !peace.at_war(proposal.from, proposal.to) {
+        return;
+    }
+
+    peace.apply(&mut commands, &mut ledger, proposal.from, proposal.to, deal);
+    println!(
+        "{} accepted peace with {}",
+        peace.name(proposal.to),
+        peace.name(proposal.from)
+    );
+}
+
+/// AI countries weigh the peace they are offered against how the war stands.
+fn ai_answer_peace_proposals(
+    mut commands: Commands,
+    mut proposals: ResMut<Proposals>,
+    ai_countries: Query<(), With<AIControlled>>,
+    peace: PeaceTable,
+) {
+    let offers = proposals.take(|proposal| {
+        matches!(proposal.kind, ProposalKind::Peace(_)) && ai_countries.contains(proposal.to)
+    });
+
+    for proposal in offers {
+        let ProposalKind::Peace(deal) = &proposal.kind else {
+            continue;
+        };
+        let accepted = peace.accepts(proposal.from, proposal.to, deal);
+        commands.trigger(ProposalAnswered { proposal, accepted });
+    }
+}
+
+fn ai_negotiate_peace(
+    mut commands: Commands,
+    ai_countries: Query<(Entity, &Opinions), With<AIControlled>>,
+    enemies: Query<(Entity, Has<AIControlled>), (With<Country>, Without<RebelFaction>)>,
+    mut peace: PeaceTable,
+    mut ledger: ResMut<BudgetLedger>,
+    mut proposals: ResMut<Proposals>,
+) {
+    let mut rng = rand::rng();
+
+    for (country_entity, opinions) in &ai_countries {
+        let possible_targets: Vec<(Entity, bool)> = enemies
+            .iter()
+            .filter(|&(e, _)| e != country_entity && peace.at_war(country_entity, e))
+            .filter(|&(e, _)| opinions.get(e) > OPINION_BLOCKS_PEACE)
+            .collect();
+
+        let Some(&(target, target_is_ai)) = possible_targets.choose(&mut rng) else {
+            continue;
+        };
+
+        // Enemies that are not hated get offered peace sooner, and long wars wear everyone down.
+        let exhaustion = peace.wars.exhaustion(country_entity, target);
+        let peace_chance =
+            (0.15 + opinions.get(target) / 200.0 + exhaustion / 200.0).clamp(0.05, 0.75);
+        if !rng.random_bool(peace_chance as f64) {
+            continue;
+        }
+
+        let deal = peace.ai_deal(country_entity, target);
+
+        // The player answers from the diplomatic inbox.
+        if !target_is_ai {
+            proposals.send(country_entity, target, ProposalKind::Peace(deal));
+            continue;
+        }
+
+        if !peace.accepts(country_entity, target, &deal) {
+            println!(
+                "{} refused peace with {}",
+                peace.name(target),
+                peace.name(country_entity)
+            );
+            continue;
+        }
+
+        peace.apply(&mut commands, &mut ledger, country_entity, target, &deal);
+
+        println!(
+            "AI country {} made peace with {} ({} provinces, {} gold demanded{})",
+            peace.name(country_entity),
+            peace.name(target),
+            deal.demanded_provinces.len(),
+            deal.demanded_gold,
+            if deal.demand_vassalage {
+                ", as a vassal"
+            } else {
+                ""
+            }
+        );
+    }
+}
+
+fn peace_negotiation_window(
+    commands_and_contexts: CommandsAndContexts,
+    negotiation: Option<ResMut<PeaceNegotiation>>,
+    peace: PeaceTable,
+    mut proposals: ResMut<Proposals>,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+) {
+    let mut commands = commands_and_contexts.commands;
+    let mut contexts = commands_and_contexts.contexts;
+
+    let Some(mut negotiation) = negotiation else {
+        return;
+    };
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let Some(player_country) = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0)
+    else {
+        return;
+    };
+
+    let target = negotiation.target;
+
+    if !peace.at_war(player_country, target) {
+        commands.remove_resource::<PeaceNegotiation>();
+        return;
+    }
+
+    let progress = peace.wars.score(player_country, target).total();
+    let their_exhaustion = peace.wars.exhaustion(target, player_country);
+    let leverage = peace.leverage(player_country, target);
+    let target_name = peace.name(target);
+    let pending = proposals.is_pending(
+        player_country,
+        target,
+        &ProposalKind::Peace(PeaceDeal::default()),
+    );
+    let can_vassalize = peace.can_vassalize(player_country, target);
+    let vassalage_cost = peace.vassalage_cost(target);
+
+    let mut their_provinces: Vec<(Entity, u32, bool)> = Vec::new();
+    let mut our_provinces: Vec<(Entity, u32)> = Vec::new();
+    for (entity, province, owned_by, occupied) in &peace.provinces {
+        if owned_by.owner == target {
+            let held = occupied.is_some_and(|occ| occ.occupier == player_country);
+            their_provinces.push((entity, province.id, held));
+        } else if owned_by.owner == player_country {
+            our_provinces.push((entity, province.id));
+        }
+    }
+    their_provinces.sort_by_key(|&(_, id, _)| id);
+    our_provinces.sort_by_key(|&(_, id)| id);
+
+    let mut open = true;
+    let mut send = false;
+
+    egui::Window::new(format!("Peace Negotiation – {}", target_name))
+        .open(&mut open)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .resizable(false)
+        .collapsible(false)
+        .show(ctx, |ui| {
+            let progress_text = format!("War score: {:+}", progress);
+            if progress >= 0 {
+                ui.colored_label(egui::Color32::LIGHT_GREEN, progress_text);
+            } else {
+                ui.colored_label(egui::Color32::LIGHT_RED, progress_text);
+            }
+            ui.label(format!(
+                "{} war exhaustion: {:.0}",
+                target_name, their_exhaustion
+            ));
+
+            ui.add_space(6.0);
+
+            let deal = &mut negotiation.deal;
+
+            ui.columns(2, |columns| {
+                columns[0].label("Demand provinces:");
+                egui::ScrollArea::vertical()
+                    .id_salt("demand_provinces")
+                    .max_height(160.0)
+                    .show(&mut columns[0], |ui| {
+                        for &(entity, id, held) in &their_provinces {
+                            let mut checked = deal.demanded_provinces.contains(&entity);
+                            let label = format!(
+                                "Province {}{}{} (cost {})",
+                                id,
+                                if held { ", occupied" } else { "" },
+                                if peace.is_claimed(player_country, entity) {
+                                    ", claimed"
+                                } else {
+                                    ""
+                                },
+                                peace.demand_cost(player_country, entity)
+                            );
+                            if ui.checkbox(&mut checked, label).changed() {
+                                toggle(&mut deal.demanded_provinces, entity, checked);
+                            }
+                        }
+                    });
+
+                columns[1].label("Offer provinces:");
+                egui::ScrollArea::vertical()
+                    .id_salt("offer_provinces")
+                    .max_height(160.0)
+                    .show(&mut columns[1], |ui| {
+                        for &(entity, id) in &our_provinces {
+                            let mut checked = deal.offered_provinces.contains(&entity);
+                            let label =
+                                format!("Province {} (worth {})", id, peace.offer_value(entity));
+                            if ui.checkbox(&mut checked, label).changed() {
+                                toggle(&mut deal.offered_provinces, entity, checked);
+                            }
+                        }
+                    });
+            });
+
+            ui.add_space(6.0);
+
+            ui.add(
+                egui::Slider::new(&mut deal.demanded_gold, 0..=peace.gold(target))
+                    .step_by(GOLD_PER_POINT as f64)
+                    .text("Demand gold"),
+            );
+            ui.add(
+                egui::Slider::new(&mut deal.offered_gold, 0..=peace.gold(player_country))
+                    .step_by(GOLD_PER_POINT as f64)
+                    .text("Offer gold"),
+            );
+
+            ui.add_enabled(
+                can_vassalize,
+                egui::Checkbox::new(
+                    &mut deal.demand_vassalage,
+                    format!("Demand vassalage (cost {})", vassalage_cost),
+                ),
+            );
+
+            if ui.button("White peace").clicked() {
+                *deal = PeaceDeal::default();
+            }
+
+            ui.separator();
+
+            let cost = peace.deal_cost(player_country, target, deal);
+            ui.label(format!(
+                "Terms cost {} of the {} {} will give up",
+                cost, leverage, target_name
+            ));
+            if deal.is_white_peace() {
+                ui.label("White peace: everyone keeps their own land");
+            }
+            if cost > leverage {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    format!("{} is unlikely to accept these terms", target_name),
+                );
+            }
+            if pending {
+                ui.label("An earlier proposal is still waiting for an answer");
+            }
+
+            ui.add_space(6.0);
+
+            if ui.button("Send proposal").clicked() {
+                send = true;
+            }
+        });
+
+    if send {
+        let deal = negotiation.deal.clone();
+        proposals.send(player_country, target, ProposalKind::Peace(deal));
+        println!("Sent peace proposal to {}", target_name);
+    }
+
+    if send || !open {
+        commands.remove_resource::<PeaceNegotiation>();
+    }
+}
+
+fn toggle(list: &mut Vec<Entity>, entity: Entity, present: bool) {
+    if present {
+        list.push(entity);
+    } else {
+        list.retain(|&e| e != entity);
+    }
+}
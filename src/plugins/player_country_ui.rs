@@ -1,5 +1,5 @@
 // plugins/ui/player_country_ui.rs
-use crate::components::country::Country;
+use crate::components::country::{Country, MAX_TAX_RATE};
 use crate::components::player::{ControlsCountry, LocalPlayer};
 use crate::states::AppState;
 use bevy::prelude::*;
@@ -53,15 +53,15 @@ fn player_country_panel_with_flag(
     mut contexts: EguiContexts,
     local_player: Option<Res<LocalPlayer>>,
     player_query: Query<&ControlsCountry>,
-    countries: Query<&Country>,
+    mut countries: Query<&mut Country>,
 ) {
     let player_country = local_player
         .and_then(|lp| player_query.get(lp.0).ok())
-        .and_then(|controls| countries.get(controls.0).ok());
+        .and_then(|controls| countries.get_mut(controls.0).ok());
 
     let mut texture_id_opt = None;
 
-    if let Some(country) = player_country {
+    if let Some(mut country) = player_country {
         if let Some(flag_handle) = &country.flag {
             texture_id_opt =
                 Some(contexts.add_image(bevy_egui::EguiTextureHandle::Strong(flag_handle.clone())));
@@ -100,6 +100,28 @@ fn player_country_panel_with_flag(
                         );
                     });
                 });
+
+                ui.separator();
+
+                let mut tax_rate = country.tax_rate;
+                ui.add(
+                    egui::Slider::new(&mut tax_rate, 0.0..=MAX_TAX_RATE)
+                        .text("Tax rate")
+                        .custom_formatter(|value, _| format!("{:.0}%", value * 100.0)),
+                );
+                if tax_rate != country.tax_rate {
+                    country.tax_rate = tax_rate;
+                }
+
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Income x{:.2}, growth {:+.1}%",
+                        country.tax_income_multiplier(),
+                        country.tax_growth_modifier() * 100.0
+                    ))
+                    .small()
+                    .color(egui::Color32::GRAY),
+                );
             });
     }
 }
@@ -0,0 +1,258 @@
+// plugins/proposals.rs
+use crate::components::country::Country;
+use crate::components::player::{ControlsCountry, LocalPlayer};
+use crate::plugins::peace::PeaceDeal;
+use crate::plugins::turn::TurnResolutionSet;
+use crate::states::AppState;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+/// Turns a proposal waits for an answer before it lapses.
+const PROPOSAL_TURNS: u32 = 3;
+
+pub struct ProposalsPlugin;
+
+impl Plugin for ProposalsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Proposals>()
+            .add_systems(Update, expire_proposals.in_set(TurnResolutionSet::End))
+            .add_systems(
+                EguiPrimaryContextPass,
+                proposals_inbox.run_if(in_state(AppState::InGame)),
+            )
+            .add_observer(on_proposal_answered_reply);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ProposalKind {
+    /// Peace on the given terms, with demands going to the sender.
+    Peace(PeaceDeal),
+    Alliance,
+    /// The sender asks to move its armies through the receiver's land.
+    MilitaryAccess,
+    /// The sender offers to take the receiver as its vassal.
+    Vassalage,
+}
+
+impl ProposalKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProposalKind::Peace(_) => "peace",
+            ProposalKind::Alliance => "an alliance",
+            ProposalKind::MilitaryAccess => "military access",
+            ProposalKind::Vassalage => "vassalage",
+        }
+    }
+
+    fn same_kind(&self, other: &ProposalKind) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+/// An offer from one country to another, waiting for the receiver's answer.
+#[derive(Clone, Debug)]
+pub struct Proposal {
+    pub from: Entity,
+    pub to: Entity,
+    pub kind: ProposalKind,
+    pub turns_left: u32,
+}
+
+/// Proposals waiting for an answer, and the answers the player has received.
+#[derive(Resource, Default)]
+pub struct Proposals {
+    pub pending: Vec<Proposal>,
+    pub replies: Vec<String>,
+}
+
+impl Proposals {
+    /// Sends a proposal, replacing an earlier one of the same kind between the two.
+    pub fn send(&mut self, from: Entity, to: Entity, kind: ProposalKind) {
+        self.pending.retain(|proposal| {
+            !(proposal.from == from && proposal.to == to && proposal.kind.same_kind(&kind))
+        });
+        self.pending.push(Proposal {
+            from,
+            to,
+            kind,
+            turns_left: PROPOSAL_TURNS,
+        });
+    }
+
+    /// Whether `from` is waiting on an answer from `to` to a proposal like `kind`.
+    pub fn is_pending(&self, from: Entity, to: Entity, kind: &ProposalKind) -> bool {
+        self.pending.iter().any(|proposal| {
+            proposal.from == from && proposal.to == to && proposal.kind.same_kind(kind)
+        })
+    }
+
+    /// Removes and returns every pending proposal that matches `filter`.
+    pub fn take(&mut self, filter: impl Fn(&Proposal) -> bool) -> Vec<Proposal> {
+        let (taken, kept): (Vec<Proposal>, Vec<Proposal>) = self
+            .pending
+            .drain(..)
+            .partition(|proposal| filter(proposal));
+        self.pending = kept;
+        taken
+    }
+}
+
+/// A proposal has been accepted or declined. The module that owns each kind of
+/// proposal carries out the accepted ones.
+#[derive(Event)]
+pub struct ProposalAnswered {
+    pub proposal: Proposal,
+    pub accepted: bool,
+}
+
+/// Tells the player how their proposals were answered.
+fn on_proposal_answered_reply(
+    trigger: On<ProposalAnswered>,
+    mut proposals: ResMut<Proposals>,
+    countries: Query<&Country>,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+) {
+    let ev = trigger.event();
+
+    let player_country = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0);
+
+    if Some(ev.proposal.from) != player_country {
+        return;
+    }
+
+    let name = countries
+        .get(ev.proposal.to)
+        .map_or("Unknown".to_string(), |c| c.name.clone());
+    proposals.replies.push(format!(
+        "{} {} our proposal of {}",
+        name,
+        if ev.accepted { "accepted" } else { "declined" },
+        ev.proposal.kind.name()
+    ));
+}
+
+fn expire_proposals(mut proposals: ResMut<Proposals>) {
+    for proposal in &mut proposals.pending {
+        proposal.turns_left = proposal.turns_left.saturating_sub(1);
+    }
+    proposals.pending.retain(|proposal| proposal.turns_left > 0);
+}
+
+fn describe(kind: &ProposalKind) -> String {
+    match kind {
+        ProposalKind::Peace(deal) => describe_peace(deal),
+        ProposalKind::Alliance => "Offers an alliance".to_string(),
+        ProposalKind::MilitaryAccess => "Asks for military access".to_string(),
+        ProposalKind::Vassalage => "Offers to take us as a vassal".to_string(),
+    }
+}
+
+fn describe_peace(deal: &PeaceDeal) -> String {
+    if deal.is_white_peace() {
+        return "Offers a white peace".to_string();
+    }
+
+    let mut terms = Vec::new();
+    if !deal.demanded_provinces.is_empty() {
+        terms.push(format!(
+            "demands {} provinces",
+            deal.demanded_provinces.len()
+        ));
+    }
+    if deal.demanded_gold > 0 {
+        terms.push(format!("demands {} gold", deal.demanded_gold));
+    }
+    if deal.demand_vassalage {
+        terms.push("demands we become a vassal".to_string());
+    }
+    if !deal.offered_provinces.is_empty() {
+        terms.push(format!("offers {} provinces", deal.offered_provinces.len()));
+    }
+    if deal.offered_gold > 0 {
+        terms.push(format!("offers {} gold", deal.offered_gold));
+    }
+    format!("Offers peace: {}", terms.join(", "))
+}
+
+fn proposals_inbox(
+    mut commands: Commands,
+    mut contexts: EguiContexts,
+    mut proposals: ResMut<Proposals>,
+    countries: Query<&Country>,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+) {
+    let Some(player_country) = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0)
+    else {
+        return;
+    };
+
+    let incoming: Vec<(usize, &Proposal)> = proposals
+        .pending
+        .iter()
+        .enumerate()
+        .filter(|(_, proposal)| proposal.to == player_country)
+        .collect();
+
+    if incoming.is_empty() && proposals.replies.is_empty() {
+        return;
+    }
+
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let name = |entity: Entity| {
+        countries
+            .get(entity)
+            .map_or("Unknown".to_string(), |c| c.name.clone())
+    };
+
+    let mut answer = None;
+    let mut dismiss = false;
+
+    egui::Window::new(format!("Diplomatic Inbox ({})", incoming.len()))
+        .id(egui::Id::new("proposals_inbox"))
+        .anchor(egui::Align2::LEFT_TOP, [10.0, 235.0])
+        .resizable(false)
+        .show(ctx, |ui| {
+            for &(index, proposal) in &incoming {
+                ui.strong(name(proposal.from));
+                ui.label(describe(&proposal.kind));
+                ui.label(format!("Expires in {} turns", proposal.turns_left));
+                ui.horizontal(|ui| {
+                    if ui.button("Accept").clicked() {
+                        answer = Some((index, true));
+                    }
+                    if ui.button("Decline").clicked() {
+                        answer = Some((index, false));
+                    }
+                });
+                ui.separator();
+            }
+
+            if !proposals.replies.is_empty() {
+                for reply in &proposals.replies {
+                    ui.label(reply);
+                }
+                if ui.button("Dismiss replies").clicked() {
+                    dismiss = true;
+                }
+            }
+        });
+
+    if let Some((index, accepted)) = answer {
+        let proposal = proposals.pending.remove(index);
+        commands.trigger(ProposalAnswered { proposal, accepted });
+    }
+
+    if dismiss {
+        proposals.replies.clear();
+    }
+}
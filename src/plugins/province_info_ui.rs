@@ -1,6 +1,9 @@
 use crate::components::buildings::Buildings;
 use crate::components::country::*;
+use crate::components::culture::{CultureMatch, religion_differs};
 use crate::components::province::*;
+use crate::misc::economy::province_economy;
+use crate::misc::unrest::{REBELLION_THRESHOLD, unrest_change};
 use crate::plugins::selection::CurrentSelection;
 use crate::plugins::selection::SelectedEntity;
 use crate::states::AppState;
@@ -22,7 +25,13 @@ impl Plugin for ProvinceInfoUI {
 fn province_info_ui(
     mut contexts: EguiContexts,
     selection: Res<CurrentSelection>,
-    provinces: Query<(&Province, Option<&OwnedBy>, &Buildings, Option<&Occupied>)>,
+    provinces: Query<(
+        &Province,
+        Option<&OwnedBy>,
+        &Buildings,
+        Option<&Occupied>,
+        Option<&Core>,
+    )>,
     countries: Query<&Country>,
 ) {
     let Ok(ctx) = contexts.ctx_mut() else {
@@ -36,11 +45,22 @@ fn province_info_ui(
         .default_size([240.0, 380.0])
         .show(ctx, |ui| {
             if let Some(SelectedEntity::Province(entity)) = selection.entity {
-                if let Ok((province, owner_opt, buildings, occupied_opt)) = provinces.get(entity) {
+                if let Ok((province, owner_opt, buildings, occupied_opt, core_opt)) =
+                    provinces.get(entity)
+                {
                     ui.heading(format!("Province {}", province.id));
                     ui.separator();
 
                     ui.label(format!("Terrain: {:?}", province.terrain));
+                    if let Some(good) = province.trade_good {
+                        ui.label(format!("Trade good: {}", good.name()));
+                    }
+                    if let Some(culture) = province.culture {
+                        ui.label(format!("Culture: {}", culture.name()));
+                    }
+                    if let Some(religion) = province.religion {
+                        ui.label(format!("Religion: {}", religion.name()));
+                    }
                     ui.label(format!(
                         "Center: {:.1}, {:.1}",
                         province.center.x, province.center.y
@@ -69,27 +89,94 @@ fn province_info_ui(
 
                     ui.separator();
 
-                    let mut province_growth = province.base_growth;
+                    let tax_rate = |entity: Entity| {
+                        countries
+                            .get(entity)
+                            .map_or(DEFAULT_TAX_RATE, |c| c.tax_rate)
+                    };
 
-                    for &building in &buildings.built {
-                        province_growth += building.growth_bonus();
-                    }
+                    let owner_country = owner_opt.and_then(|o| countries.get(o.owner).ok());
+                    let owner_culture = owner_country.and_then(|c| c.culture);
+                    let owner_religion = owner_country.and_then(|c| c.religion);
 
-                    let is_occupied = occupied_opt.is_some();
-                    if is_occupied {
-                        province_growth = -0.05;
-                    }
-
-                    let growth_amount =
-                        (province.population as f32 * province_growth).round() as i32;
+                    let economy = province_economy(
+                        province,
+                        buildings,
+                        owner_opt.map_or(DEFAULT_TAX_RATE, |o| tax_rate(o.owner)),
+                        occupied_opt.map(|occ| tax_rate(occ.occupier)),
+                        owner_culture,
+                    );
 
                     ui.label(format!("Population: {}", province.population));
                     ui.label(format!(
-                        "Growth per turn: {}% ({} people)",
-                        province_growth * 100.0,
-                        growth_amount,
-                        //(province.population as f32 * province.base_growth).round() as i32
+                        "Carrying capacity: {} (room for {} more)",
+                        economy.capacity,
+                        economy.capacity.saturating_sub(province.population)
                     ));
+                    ui.label(format!(
+                        "Growth per turn: {:.1}% ({} people)",
+                        economy.growth_rate * 100.0,
+                        economy.population_change,
+                    ));
+                    if economy.is_occupied {
+                        ui.label("Occupied: population declining");
+                    } else {
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "Base {:.1}%, buildings {:+.1}%, taxes {:+.1}%, crowding x{:.2}",
+                                economy.base_growth * 100.0,
+                                economy.building_growth * 100.0,
+                                economy.tax_growth * 100.0,
+                                economy.capacity_factor
+                            ))
+                            .small()
+                            .color(egui::Color32::GRAY),
+                        );
+                    }
+
+                    ui.separator();
+
+                    let owner_tax_rate = owner_opt.map_or(DEFAULT_TAX_RATE, |o| tax_rate(o.owner));
+                    let is_foreign = match (owner_opt, core_opt) {
+                        (Some(owner), Some(core)) => core.country != owner.owner,
+                        _ => false,
+                    };
+                    let unrest = unrest_change(
+                        occupied_opt.is_some(),
+                        is_foreign,
+                        owner_tax_rate,
+                        CultureMatch::between(province.culture, owner_culture),
+                        religion_differs(province.religion, owner_religion),
+                    );
+
+                    let unrest_text = format!(
+                        "Unrest: {:.0}% ({:+.1}/turn)",
+                        province.unrest, unrest.total
+                    );
+                    if province.unrest >= REBELLION_THRESHOLD {
+                        ui.colored_label(egui::Color32::RED, unrest_text);
+                    } else {
+                        ui.label(unrest_text);
+                    }
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "Occupation {:+.1}, foreign rule {:+.1}, taxes {:+.1}, culture {:+.1}, religion {:+.1}, calming {:+.1}",
+                            unrest.occupation,
+                            unrest.foreign_rule,
+                            unrest.taxes,
+                            unrest.culture,
+                            unrest.religion,
+                            unrest.decay
+                        ))
+                        .small()
+                        .color(egui::Color32::GRAY),
+                    );
+                    if let Some(core_country) = core_opt.and_then(|c| countries.get(c.country).ok())
+                    {
+                        ui.label(format!("Core of: {}", core_country.name));
+                    }
+
+                    ui.separator();
 
                     if !buildings.built.is_empty() {
                         ui.label("Buildings:");
@@ -102,23 +189,28 @@ fn province_info_ui(
 
                     ui.separator();
 
-                    let mut income = province.base_income as f32;
-
-                    for &building in &buildings.built {
-                        income += building.income_bonus() as f32;
-                    }
-
-                    income += (province.population / 1000) as f32;
-
-                    if occupied_opt.is_some() {
-                        income *= 0.6;
-                        ui.label("Occupied: income reduced");
-                    }
-
                     ui.label(format!(
                         "Estimated income this turn: {} gold",
-                        income.round() as u32
+                        economy.income
                     ));
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "Base {}, buildings {}, population {}, taxes x{:.2}, culture x{:.2}",
+                            economy.base_income,
+                            economy.building_income,
+                            economy.population_income,
+                            economy.tax_multiplier,
+                            economy.culture_multiplier
+                        ))
+                        .small()
+                        .color(egui::Color32::GRAY),
+                    );
+                    if economy.is_occupied {
+                        ui.label(format!(
+                            "Occupied: income x{:.1} to the occupier",
+                            economy.occupation_multiplier
+                        ));
+                    }
 
                     ui.separator();
 
@@ -32,6 +32,8 @@ enum MapMode {
     #[default]
     Political,
     Terrain,
+    Culture,
+    Religion,
 }
 
 #[derive(Resource)]
@@ -46,8 +48,10 @@ impl Default for BordersVisible {
 fn toggle_map_mode(keyboard: Res<ButtonInput<KeyCode>>, mut map_mode: ResMut<MapMode>) {
     if keyboard.just_pressed(KeyCode::KeyM) {
         *map_mode = match *map_mode {
-            MapMode::Terrain => MapMode::Political,
             MapMode::Political => MapMode::Terrain,
+            MapMode::Terrain => MapMode::Culture,
+            MapMode::Culture => MapMode::Religion,
+            MapMode::Religion => MapMode::Political,
         };
         println!("Map mode switched to: {:?}", *map_mode);
     }
@@ -64,7 +68,7 @@ fn update_changed_province_colors(
     map_mode: Res<MapMode>,
     changed_provinces: Query<ProvinceQuery>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    countries: Query<&Country>,
+    countries: Query<(&Country, Option<&VassalOf>)>,
 ) {
     for (province, material_handle, owner_opt, occupied_opt) in &changed_provinces {
         let Some(material) = materials.get_mut(&material_handle.0) else {
@@ -79,9 +83,17 @@ fn update_changed_province_colors(
         let color = match *map_mode {
             MapMode::Terrain => province.terrain.color(),
 
+            MapMode::Culture => province
+                .culture
+                .map_or(Color::srgb(0.5, 0.5, 0.5), |c| c.color()),
+
+            MapMode::Religion => province
+                .religion
+                .map_or(Color::srgb(0.5, 0.5, 0.5), |r| r.color()),
+
             MapMode::Political => {
                 if let Some(occupied) = occupied_opt {
-                    if let Ok(occ_country) = countries.get(occupied.occupier) {
+                    if let Ok((occ_country, _)) = countries.get(occupied.occupier) {
                         match occ_country.color {
                             Color::Srgba(s) => Color::srgba(
                                 (s.red * 0.65 + 0.12).clamp(0.0, 1.0),
@@ -95,10 +107,15 @@ fn update_changed_province_colors(
                         Color::srgb(0.8, 0.1, 0.1)
                     }
                 } else if let Some(owner) = owner_opt {
-                    countries
-                        .get(owner.owner)
-                        .map(|c| c.color)
-                        .unwrap_or(Color::srgb(0.0, 0.0, 1.0))
+                    match countries.get(owner.owner) {
+                        Ok((country, Some(vassal_of))) => countries
+                            .get(vassal_of.overlord)
+                            .map_or(country.color, |(overlord, _)| {
+                                vassal_color(country.color, overlord.color)
+                            }),
+                        Ok((country, None)) => country.color,
+                        Err(_) => Color::srgb(0.0, 0.0, 1.0),
+                    }
                 } else {
                     Color::srgb(0.0, 0.0, 1.0)
                 }
@@ -109,6 +126,19 @@ fn update_changed_province_colors(
     }
 }
 
+/// Vassals are painted in their overlord's colour, tinted with a little of their own.
+fn vassal_color(own: Color, overlord: Color) -> Color {
+    match (own, overlord) {
+        (Color::Srgba(o), Color::Srgba(v)) => Color::srgba(
+            v.red * 0.7 + o.red * 0.3,
+            v.green * 0.7 + o.green * 0.3,
+            v.blue * 0.7 + o.blue * 0.3,
+            v.alpha,
+        ),
+        _ => overlord,
+    }
+}
+
 fn toggle_borders(
     keyboard: Res<ButtonInput<KeyCode>>,
     mut borders_visible: ResMut<BordersVisible>,
@@ -1,9 +1,22 @@
 // plugins/save_load.rs
 use crate::components::army::*;
 use crate::components::country::*;
+use crate::components::culture::{Culture, Religion};
+use crate::components::debt::Debt;
+use crate::components::events::QueuedEvents;
+use crate::components::opinion::{OpinionModifier, Opinions};
+use crate::components::personality::Personality;
 use crate::components::player::*;
 use crate::components::province::*;
+use crate::misc::difficulty::Difficulty;
+use crate::plugins::claims::{Claims, Fabrication};
+use crate::plugins::coalitions::{Coalition, Coalitions};
 use crate::plugins::map_generation::{MapGenerated, ProvinceEntityMap};
+use crate::plugins::military_access::MilitaryAccess;
+use crate::plugins::proposals::Proposals;
+use crate::plugins::truces::{Truce, Truces};
+use crate::plugins::victory::{CountryStats, GameStats};
+use crate::plugins::war_score::{War, Wars};
 use crate::states::AppState;
 use anyhow::{Context, Result};
 use bevy::platform::collections::HashMap;
@@ -30,12 +43,58 @@ impl Plugin for SaveLoadPlugin {
 #[derive(Resource)]
 pub struct SaveFilePath(pub String);
 
+type ProvinceSaveQuery<'a> = (
+    &'a Province,
+    Option<&'a OwnedBy>,
+    Option<&'a Core>,
+    Option<&'a Occupied>,
+);
+
+type CountrySaveQuery<'a> = (
+    &'a Country,
+    &'a Relations,
+    &'a Debt,
+    &'a Grievances,
+    &'a Opinions,
+    Option<&'a VassalOf>,
+    Option<&'a Personality>,
+);
+
 #[derive(Serialize, Deserialize)]
 pub struct SaveData {
     pub countries: Vec<CountrySaveData>,
     pub armies: Vec<ArmySaveData>,
     pub occupied_provinces: Vec<OccupiedData>,
     pub player_country_id: Option<u32>,
+    #[serde(default)]
+    pub cores: Vec<CoreData>,
+    #[serde(default)]
+    pub wars: Vec<WarSaveData>,
+    #[serde(default)]
+    pub truces: Vec<TruceSaveData>,
+    /// (granter id, grantee id) pairs.
+    #[serde(default)]
+    pub military_access: Vec<(u32, u32)>,
+    /// (claimant id, province id) pairs.
+    #[serde(default)]
+    pub claims: Vec<(u32, u32)>,
+    #[serde(default)]
+    pub fabrications: Vec<FabricationSaveData>,
+    /// Aggressive expansion by country id.
+    #[serde(default)]
+    pub aggressive_expansion: HashMap<u32, f32>,
+    #[serde(default)]
+    pub coalitions: Vec<CoalitionSaveData>,
+    #[serde(default)]
+    pub turn: u32,
+    /// Running tallies by country id.
+    #[serde(default)]
+    pub statistics: HashMap<u32, CountryStats>,
+    /// Eliminated countries and the turn each fell.
+    #[serde(default)]
+    pub eliminated: Vec<(String, u32)>,
+    #[serde(default)]
+    pub difficulty: Difficulty,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -45,9 +104,29 @@ pub struct CountrySaveData {
     #[serde(with = "color_serde")]
     pub color: Color,
     pub gold: u32,
+    #[serde(default = "default_tax_rate")]
+    pub tax_rate: f32,
     pub owned_provinces: Vec<u32>,
     pub flag_path: Option<String>,
+    #[serde(default)]
+    pub culture: Option<Culture>,
+    #[serde(default)]
+    pub religion: Option<Religion>,
     pub relations: HashMap<u32, Relation>,
+    #[serde(default)]
+    pub debt: Debt,
+    #[serde(default)]
+    pub grievances: HashMap<u32, f32>,
+    #[serde(default)]
+    pub opinions: HashMap<u32, Vec<OpinionModifier>>,
+    #[serde(default)]
+    pub overlord: Option<u32>,
+    #[serde(default)]
+    pub personality: Personality,
+}
+
+fn default_tax_rate() -> f32 {
+    DEFAULT_TAX_RATE
 }
 
 #[derive(Serialize, Deserialize)]
@@ -63,6 +142,41 @@ pub struct OccupiedData {
     pub occupier_id: u32,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct CoreData {
+    pub province_id: u32,
+    pub country_id: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WarSaveData {
+    pub side_ids: [u32; 2],
+    pub turns: u32,
+    pub battles_won: [u32; 2],
+    pub occupation: i32,
+    pub ticking: i32,
+    pub exhaustion: [f32; 2],
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TruceSaveData {
+    pub side_ids: [u32; 2],
+    pub turns_left: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FabricationSaveData {
+    pub claimant_id: u32,
+    pub province_id: u32,
+    pub turns_left: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CoalitionSaveData {
+    pub target_id: u32,
+    pub member_ids: Vec<u32>,
+}
+
 #[derive(Resource, Default)]
 pub struct SaveLoadError {
     pub message: Option<String>,
@@ -115,6 +229,8 @@ fn initialize_new_game(
         }
     };
 
+    let rebel_faction = spawn_rebel_faction(&mut commands);
+
     let mut country_entities = HashMap::new();
     for country_def in &country_defs {
         let flag = country_def
@@ -129,10 +245,17 @@ fn initialize_new_game(
                 color: country_def.color,
                 owned_provinces: country_def.owned_provinces.clone(),
                 gold: country_def.gold,
+                tax_rate: DEFAULT_TAX_RATE,
+                culture: country_def.culture,
+                religion: country_def.religion,
                 flag,
                 flag_path: country_def.flag_path.clone(),
             },
-            Relations::default(),
+            rebel_relations(rebel_faction),
+            Debt::default(),
+            Grievances::default(),
+            Opinions::default(),
+            country_def.personality,
         ));
 
         builder.insert(AIControlled);
@@ -142,18 +265,43 @@ fn initialize_new_game(
         country_entities.insert(country_def.id, country_entity);
     }
 
+    let mut claims = Claims::default();
     for country_def in &country_defs {
         if let Some(&country_entity) = country_entities.get(&country_def.id) {
+            for province_id in &country_def.claims {
+                if let Some(&province_entity) = province_map.0.get(province_id) {
+                    claims.add(country_entity, province_entity);
+                }
+            }
+
             for &province_id in &country_def.owned_provinces {
                 if let Some(&province_entity) = province_map.0.get(&province_id) {
-                    commands.entity(province_entity).insert(OwnedBy {
-                        owner: country_entity,
-                    });
+                    commands.entity(province_entity).insert((
+                        OwnedBy {
+                            owner: country_entity,
+                        },
+                        Core {
+                            country: country_entity,
+                        },
+                    ));
                 }
             }
         }
     }
 
+    commands
+        .entity(rebel_faction)
+        .insert(at_war_with_all(country_entities.values()));
+
+    commands.insert_resource(Wars::default());
+    commands.insert_resource(Truces::default());
+    commands.insert_resource(MilitaryAccess::default());
+    commands.insert_resource(claims);
+    commands.insert_resource(Proposals::default());
+    commands.insert_resource(QueuedEvents::default());
+    commands.insert_resource(Coalitions::default());
+    commands.insert_resource(GameStats::default());
+
     next_state.set(AppState::CountrySelection);
 }
 
@@ -208,13 +356,22 @@ fn load_and_apply_save(
                 color: country_data.color,
                 owned_provinces: country_data.owned_provinces.clone(),
                 gold: country_data.gold,
+                tax_rate: country_data.tax_rate,
+                culture: country_data.culture,
+                religion: country_data.religion,
                 flag,
                 flag_path: country_data.flag_path.clone(),
             },
             Relations::default(),
+            country_data.debt.clone(),
+            Grievances::default(),
+            Opinions::default(),
+            country_data.personality,
         ));
 
-        if Some(country_data.id) != save_data.player_country_id {
+        if country_data.id == REBEL_COUNTRY_ID {
+            builder.insert(RebelFaction);
+        } else if Some(country_data.id) != save_data.player_country_id {
             builder.insert(AIControlled);
         }
 
@@ -222,19 +379,60 @@ fn load_and_apply_save(
         country_entities.insert(country_data.id, country_entity);
     }
 
+    // Saves made before rebels existed have no rebel faction yet.
+    let rebel_faction = match country_entities.get(&REBEL_COUNTRY_ID) {
+        Some(&entity) => entity,
+        None => spawn_rebel_faction(commands),
+    };
+
+    commands.entity(rebel_faction).insert(at_war_with_all(
+        country_entities.values().filter(|&&e| e != rebel_faction),
+    ));
+
     for country_data in &save_data.countries {
         let country_entity = *country_entities
             .get(&country_data.id)
             .context("Country entity not found")?;
 
-        let mut relations = Relations::default();
+        if country_entity == rebel_faction {
+            continue;
+        }
+
+        let mut relations = rebel_relations(rebel_faction);
         for (&other_id, &relation_data) in &country_data.relations {
             if let Some(&other_entity) = country_entities.get(&other_id) {
                 relations.set(other_entity, relation_data);
             }
         }
 
-        commands.entity(country_entity).insert(relations);
+        let mut grievances = Grievances::default();
+        for (&other_id, &grievance) in &country_data.grievances {
+            if let Some(&other_entity) = country_entities.get(&other_id) {
+                grievances.add(other_entity, grievance);
+            }
+        }
+
+        let mut opinions = Opinions::default();
+        for (&other_id, modifiers) in &country_data.opinions {
+            if let Some(&other_entity) = country_entities.get(&other_id) {
+                for modifier in modifiers {
+                    opinions.add(other_entity, modifier.kind, modifier.value);
+                }
+            }
+        }
+
+        commands
+            .entity(country_entity)
+            .insert((relations, grievances, opinions));
+
+        if let Some(&overlord) = country_data
+            .overlord
+            .and_then(|id| country_entities.get(&id))
+        {
+            commands
+                .entity(country_entity)
+                .insert(VassalOf { overlord });
+        }
     }
 
     for country_data in &save_data.countries {
@@ -254,6 +452,35 @@ fn load_and_apply_save(
         }
     }
 
+    for core_data in &save_data.cores {
+        let province_entity = *province_map
+            .0
+            .get(&core_data.province_id)
+            .with_context(|| format!("Core province {} not found", core_data.province_id))?;
+
+        let country_entity = *country_entities
+            .get(&core_data.country_id)
+            .with_context(|| format!("Core country {} not found", core_data.country_id))?;
+
+        commands.entity(province_entity).insert(Core {
+            country: country_entity,
+        });
+    }
+
+    // Older saves have no cores; treat every province as a core of its owner.
+    if save_data.cores.is_empty() {
+        for country_data in &save_data.countries {
+            let country_entity = country_entities[&country_data.id];
+            for province_id in &country_data.owned_provinces {
+                if let Some(&province_entity) = province_map.0.get(province_id) {
+                    commands.entity(province_entity).insert(Core {
+                        country: country_entity,
+                    });
+                }
+            }
+        }
+    }
+
     for army_data in &save_data.armies {
         let owner_entity = *country_entities
             .get(&army_data.owner_id)
@@ -299,6 +526,105 @@ fn load_and_apply_save(
         });
     }
 
+    let mut wars = Wars::default();
+    for war_data in &save_data.wars {
+        let [Some(&a), Some(&b)] = war_data.side_ids.map(|id| country_entities.get(&id)) else {
+            continue;
+        };
+
+        wars.wars.push(War {
+            sides: [a, b],
+            turns: war_data.turns,
+            battles_won: war_data.battles_won,
+            occupation: war_data.occupation,
+            ticking: war_data.ticking,
+            exhaustion: war_data.exhaustion,
+        });
+    }
+    commands.insert_resource(wars);
+
+    let mut truces = Truces::default();
+    for truce_data in &save_data.truces {
+        let [Some(&a), Some(&b)] = truce_data.side_ids.map(|id| country_entities.get(&id)) else {
+            continue;
+        };
+
+        truces.truces.push(Truce {
+            sides: [a, b],
+            turns_left: truce_data.turns_left,
+        });
+    }
+    commands.insert_resource(truces);
+
+    let mut access = MilitaryAccess::default();
+    for (granter_id, grantee_id) in &save_data.military_access {
+        if let (Some(&granter), Some(&grantee)) = (
+            country_entities.get(granter_id),
+            country_entities.get(grantee_id),
+        ) {
+            access.grant(granter, grantee);
+        }
+    }
+    commands.insert_resource(access);
+
+    let mut claims = Claims::default();
+    for (claimant_id, province_id) in &save_data.claims {
+        if let (Some(&claimant), Some(&province)) = (
+            country_entities.get(claimant_id),
+            province_map.0.get(province_id),
+        ) {
+            claims.add(claimant, province);
+        }
+    }
+    for fabrication_data in &save_data.fabrications {
+        if let (Some(&claimant), Some(&province)) = (
+            country_entities.get(&fabrication_data.claimant_id),
+            province_map.0.get(&fabrication_data.province_id),
+        ) {
+            claims.fabrications.push(Fabrication {
+                claimant,
+                province,
+                turns_left: fabrication_data.turns_left,
+            });
+        }
+    }
+    commands.insert_resource(claims);
+    // Proposals are not saved; any still waiting when the game was saved have lapsed.
+    commands.insert_resource(Proposals::default());
+    commands.insert_resource(QueuedEvents::default());
+
+    let mut coalitions = Coalitions::default();
+    for (country_id, &expansion) in &save_data.aggressive_expansion {
+        if let Some(&country) = country_entities.get(country_id) {
+            coalitions.expansion.insert(country, expansion);
+        }
+    }
+    for coalition_data in &save_data.coalitions {
+        let Some(&target) = country_entities.get(&coalition_data.target_id) else {
+            continue;
+        };
+        coalitions.coalitions.push(Coalition {
+            target,
+            members: coalition_data
+                .member_ids
+                .iter()
+                .filter_map(|id| country_entities.get(id).copied())
+                .collect(),
+        });
+    }
+    commands.insert_resource(coalitions);
+
+    let mut stats = GameStats::default();
+    stats.turn = save_data.turn;
+    stats.eliminated = save_data.eliminated.clone();
+    for (country_id, country_stats) in &save_data.statistics {
+        if let Some(&country) = country_entities.get(country_id) {
+            stats.countries.insert(country, *country_stats);
+        }
+    }
+    commands.insert_resource(stats);
+    commands.insert_resource(save_data.difficulty);
+
     if let Some(saved_country_id) = save_data.player_country_id {
         if let Some(&country_entity) = country_entities.get(&saved_country_id) {
             let player_entity = commands
@@ -327,10 +653,18 @@ fn load_and_apply_save(
 
 fn save_game_on_key(
     keyboard: Res<ButtonInput<KeyCode>>,
-    countries: Query<(&Country, &Relations)>,
+    countries: Query<CountrySaveQuery>,
     armies: Query<&Army>,
-    provinces: Query<(&Province, Option<&OwnedBy>)>,
-    occupied_provinces: Query<(&Province, &Occupied)>,
+    provinces: Query<ProvinceSaveQuery>,
+    (wars, truces, access, claims, coalitions, stats, difficulty): (
+        Res<Wars>,
+        Res<Truces>,
+        Res<MilitaryAccess>,
+        Res<Claims>,
+        Res<Coalitions>,
+        Res<GameStats>,
+        Res<Difficulty>,
+    ),
     local_player: Option<Res<LocalPlayer>>,
     player_query: Query<&ControlsCountry>,
 ) {
@@ -343,7 +677,15 @@ fn save_game_on_key(
         &countries,
         &armies,
         &provinces,
-        &occupied_provinces,
+        (
+            &wars,
+            &truces,
+            &access,
+            &claims,
+            &coalitions,
+            &stats,
+            *difficulty,
+        ),
         local_player,
         &player_query,
     ) {
@@ -378,19 +720,28 @@ fn save_to_disk(save_data: &SaveData, path: &str) -> Result<()> {
 }
 
 fn collect_save_data(
-    countries: &Query<(&Country, &Relations)>,
+    countries: &Query<CountrySaveQuery>,
     armies: &Query<&Army>,
-    provinces: &Query<(&Province, Option<&OwnedBy>)>,
-    occupied_provinces: &Query<(&Province, &Occupied)>,
+    provinces: &Query<ProvinceSaveQuery>,
+    (wars, truces, access, claims, coalitions, stats, difficulty): (
+        &Wars,
+        &Truces,
+        &MilitaryAccess,
+        &Claims,
+        &Coalitions,
+        &GameStats,
+        Difficulty,
+    ),
     local_player: Option<Res<LocalPlayer>>,
     player_query: &Query<&ControlsCountry>,
 ) -> Result<SaveData, anyhow::Error> {
     let mut country_data = Vec::new();
 
-    for (country, relations) in countries.iter() {
+    for (country, relations, debt, grievances, opinions, vassal_of, personality) in countries.iter()
+    {
         let owned_provinces: Vec<u32> = provinces
             .iter()
-            .filter_map(|(province, owner)| {
+            .filter_map(|(province, owner, ..)| {
                 owner.and_then(|o| {
                     if countries.get(o.owner).ok()?.0.id == country.id {
                         Some(province.id)
@@ -403,25 +754,49 @@ fn collect_save_data(
 
         let mut relation_map = HashMap::new();
         for (other_entity, relation) in &relations.relations {
-            if let Ok((other_country, _)) = countries.get(*other_entity) {
+            if let Ok((other_country, ..)) = countries.get(*other_entity) {
                 relation_map.insert(other_country.id, *relation);
             }
         }
 
+        let mut grievance_map = HashMap::new();
+        for (other_entity, grievance) in &grievances.against {
+            if let Ok((other_country, ..)) = countries.get(*other_entity) {
+                grievance_map.insert(other_country.id, *grievance);
+            }
+        }
+
+        let mut opinion_map = HashMap::new();
+        for (other_entity, modifiers) in &opinions.of {
+            if let Ok((other_country, ..)) = countries.get(*other_entity) {
+                opinion_map.insert(other_country.id, modifiers.clone());
+            }
+        }
+
         country_data.push(CountrySaveData {
             id: country.id,
             name: country.name.clone(),
             color: country.color,
             owned_provinces,
             gold: country.gold,
+            tax_rate: country.tax_rate,
             flag_path: country.flag_path.clone(),
+            culture: country.culture,
+            religion: country.religion,
             relations: relation_map,
+            debt: debt.clone(),
+            grievances: grievance_map,
+            opinions: opinion_map,
+            overlord: vassal_of
+                .and_then(|v| countries.get(v.overlord).ok())
+                .map(|(overlord, ..)| overlord.id),
+            personality: personality.copied().unwrap_or_default(),
         });
     }
 
     let mut army_data = Vec::new();
     for army in armies.iter() {
-        if let (Ok((owner_country, _)), Ok((province, _))) =
+        if let (Ok((owner_country, ..)), Ok((province, ..))) =
             (countries.get(army.owner), provinces.get(army.province))
         {
             army_data.push(ArmySaveData {
@@ -433,8 +808,12 @@ fn collect_save_data(
     }
 
     let mut occupied_data = Vec::new();
-    for (province, occupied) in occupied_provinces.iter() {
-        if let Ok((occupier_country, _)) = countries.get(occupied.occupier) {
+    for (province, _, _, occupied) in provinces.iter() {
+        let Some(occupied) = occupied else {
+            continue;
+        };
+
+        if let Ok((occupier_country, ..)) = countries.get(occupied.occupier) {
             occupied_data.push(OccupiedData {
                 province_id: province.id,
                 occupier_id: occupier_country.id,
@@ -442,12 +821,116 @@ fn collect_save_data(
         }
     }
 
+    let mut core_data = Vec::new();
+    for (province, _, core, _) in provinces.iter() {
+        let Some(core) = core else {
+            continue;
+        };
+
+        if let Ok((country, ..)) = countries.get(core.country) {
+            core_data.push(CoreData {
+                province_id: province.id,
+                country_id: country.id,
+            });
+        }
+    }
+
+    let mut war_data = Vec::new();
+    for war in &wars.wars {
+        if let (Ok((a, ..)), Ok((b, ..))) =
+            (countries.get(war.sides[0]), countries.get(war.sides[1]))
+        {
+            war_data.push(WarSaveData {
+                side_ids: [a.id, b.id],
+                turns: war.turns,
+                battles_won: war.battles_won,
+                occupation: war.occupation,
+                ticking: war.ticking,
+                exhaustion: war.exhaustion,
+            });
+        }
+    }
+
+    let mut truce_data = Vec::new();
+    for truce in &truces.truces {
+        if let (Ok((a, ..)), Ok((b, ..))) =
+            (countries.get(truce.sides[0]), countries.get(truce.sides[1]))
+        {
+            truce_data.push(TruceSaveData {
+                side_ids: [a.id, b.id],
+                turns_left: truce.turns_left,
+            });
+        }
+    }
+
+    let mut access_data = Vec::new();
+    for &(granter, grantee) in &access.grants {
+        if let (Ok((granter, ..)), Ok((grantee, ..))) =
+            (countries.get(granter), countries.get(grantee))
+        {
+            access_data.push((granter.id, grantee.id));
+        }
+    }
+
+    let mut claim_data = Vec::new();
+    for &(claimant, province) in &claims.claims {
+        if let (Ok((claimant, ..)), Ok((province, ..))) =
+            (countries.get(claimant), provinces.get(province))
+        {
+            claim_data.push((claimant.id, province.id));
+        }
+    }
+
+    let mut fabrication_data = Vec::new();
+    for fabrication in &claims.fabrications {
+        if let (Ok((claimant, ..)), Ok((province, ..))) = (
+            countries.get(fabrication.claimant),
+            provinces.get(fabrication.province),
+        ) {
+            fabrication_data.push(FabricationSaveData {
+                claimant_id: claimant.id,
+                province_id: province.id,
+                turns_left: fabrication.turns_left,
+            });
+        }
+    }
+
+    let mut expansion_data = HashMap::new();
+    for (country, &expansion) in &coalitions.expansion {
+        if let Ok((country, ..)) = countries.get(*country) {
+            expansion_data.insert(country.id, expansion);
+        }
+    }
+
+    let mut coalition_data = Vec::new();
+    for coalition in &coalitions.coalitions {
+        let Ok((target, ..)) = countries.get(coalition.target) else {
+            continue;
+        };
+        coalition_data.push(CoalitionSaveData {
+            target_id: target.id,
+            member_ids: coalition
+                .members
+                .iter()
+                .filter_map(|&member| countries.get(member).ok())
+                .map(|(member, ..)| member.id)
+                .collect(),
+        });
+    }
+
+    let mut statistics = HashMap::new();
+    for (country, country_stats) in &stats.countries {
+        if let Ok((country, ..)) = countries.get(*country) {
+            statistics.insert(country.id, *country_stats);
+        }
+    }
+
     let player_country_id = local_player.and_then(|lp| {
         player_query
             .get(lp.0)
             .ok()
             .and_then(|controls| countries.get(controls.0).ok())
-            .map(|(country, _)| country.id)
+            .map(|(country, ..)| country.id)
     });
 
     Ok(SaveData {
@@ -455,9 +938,59 @@ fn collect_save_data(
         armies: army_data,
         occupied_provinces: occupied_data,
         player_country_id,
+        cores: core_data,
+        wars: war_data,
+        truces: truce_data,
+        military_access: access_data,
+        claims: claim_data,
+        fabrications: fabrication_data,
+        aggressive_expansion: expansion_data,
+        coalitions: coalition_data,
+        turn: stats.turn,
+        statistics,
+        eliminated: stats.eliminated.clone(),
+        difficulty,
     })
 }
 
+fn spawn_rebel_faction(commands: &mut Commands) -> Entity {
+    commands
+        .spawn((
+            Country {
+                id: REBEL_COUNTRY_ID,
+                name: "Rebels".to_string(),
+                color: Color::srgb(0.15, 0.15, 0.15),
+                owned_provinces: Vec::new(),
+                gold: 0,
+                tax_rate: DEFAULT_TAX_RATE,
+                culture: None,
+                religion: None,
+                flag: None,
+                flag_path: None,
+            },
+            Relations::default(),
+            Debt::default(),
+            Grievances::default(),
+            Opinions::default(),
+            RebelFaction,
+        ))
+        .id()
+}
+
+fn rebel_relations(rebel_faction: Entity) -> Relations {
+    let mut relations = Relations::default();
+    relations.set(rebel_faction, Relation::War);
+    relations
+}
+
+fn at_war_with_all<'a>(countries: impl Iterator<Item = &'a Entity>) -> Relations {
+    let mut relations = Relations::default();
+    for &country in countries {
+        relations.set(country, Relation::War);
+    }
+    relations
+}
+
 fn load_save_file(path: &str) -> Result<SaveData> {
     let file = std::fs::read_to_string(path)
         .with_context(|| format!("Failed to read save file at '{}'", path))?;
@@ -0,0 +1,199 @@
+// plugins/trade.rs
+use crate::components::country::{Country, Relation, Relations};
+use crate::components::player::{ControlsCountry, LocalPlayer};
+use crate::components::province::{Occupied, OwnedBy, Province};
+use crate::components::trade::{ALL_TRADE_GOODS, TradeGood};
+use crate::plugins::ledger::BudgetLedger;
+use crate::plugins::turn::TurnResolutionSet;
+use crate::resources::TradePrices;
+use crate::states::AppState;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, EguiPrimaryContextPass, egui};
+
+/// Units of each good a country consumes itself before it has anything to export.
+const DOMESTIC_DEMAND: u32 = 1;
+
+pub struct TradePlugin;
+
+impl Plugin for TradePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TradeReport>()
+            .add_systems(Update, process_trade.in_set(TurnResolutionSet::Economy))
+            .add_systems(
+                EguiPrimaryContextPass,
+                trade_panel_ui.run_if(in_state(AppState::InGame)),
+            );
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct CountryTrade {
+    pub produced: HashMap<TradeGood, u32>,
+    pub exported: HashMap<TradeGood, u32>,
+    pub imported: HashMap<TradeGood, u32>,
+    pub income: HashMap<TradeGood, u32>,
+}
+
+impl CountryTrade {
+    pub fn produced(&self, good: TradeGood) -> u32 {
+        self.produced.get(&good).copied().unwrap_or(0)
+    }
+
+    pub fn total_income(&self) -> u32 {
+        self.income.values().sum()
+    }
+}
+
+/// Result of the last turn's trade, per country.
+#[derive(Resource, Default)]
+pub struct TradeReport {
+    pub countries: HashMap<Entity, CountryTrade>,
+}
+
+fn process_trade(
+    provinces: Query<(Entity, &Province, &OwnedBy)>,
+    occupied: Query<&Occupied>,
+    mut countries: Query<(Entity, &mut Country, &Relations)>,
+    prices: Res<TradePrices>,
+    mut report: ResMut<TradeReport>,
+    mut ledger: ResMut<BudgetLedger>,
+) {
+    let mut trade: HashMap<Entity, CountryTrade> = countries
+        .iter()
+        .map(|(entity, _, _)| (entity, CountryTrade::default()))
+        .collect();
+
+    for (prov_entity, province, owned_by) in &provinces {
+        let Some(good) = province.trade_good else {
+            continue;
+        };
+
+        let controller = occupied
+            .get(prov_entity)
+            .map_or(owned_by.owner, |occ| occ.occupier);
+
+        if let Some(country_trade) = trade.get_mut(&controller) {
+            *country_trade.produced.entry(good).or_insert(0) += 1;
+        }
+    }
+
+    for good in ALL_TRADE_GOODS {
+        let price = prices.price(good);
+
+        let mut surplus: HashMap<Entity, u32> = trade
+            .iter()
+            .filter(|(_, t)| t.produced(good) > DOMESTIC_DEMAND)
+            .map(|(&entity, t)| (entity, t.produced(good) - DOMESTIC_DEMAND))
+            .collect();
+
+        let mut importers: Vec<Entity> = trade
+            .iter()
+            .filter(|(_, t)| t.produced(good) < DOMESTIC_DEMAND)
+            .map(|(&entity, _)| entity)
+            .collect();
+        importers.sort();
+
+        for importer in importers {
+            let Ok((_, _, importer_relations)) = countries.get(importer) else {
+                continue;
+            };
+
+            let Some(exporter) = surplus
+                .iter()
+                .filter(|&(&exporter, &units)| {
+                    units > 0 && importer_relations.get(exporter) != Relation::War
+                })
+                .max_by_key(|&(&exporter, &units)| (units, exporter))
+                .map(|(&exporter, _)| exporter)
+            else {
+                continue;
+            };
+
+            if let Some(units) = surplus.get_mut(&exporter) {
+                *units -= 1;
+            }
+
+            if let Some(exporter_trade) = trade.get_mut(&exporter) {
+                *exporter_trade.exported.entry(good).or_insert(0) += 1;
+                *exporter_trade.income.entry(good).or_insert(0) += price;
+            }
+
+            if let Some(importer_trade) = trade.get_mut(&importer) {
+                *importer_trade.imported.entry(good).or_insert(0) += 1;
+            }
+        }
+    }
+
+    for (country_entity, mut country, _) in &mut countries {
+        if let Some(country_trade) = trade.get(&country_entity) {
+            country.gold += country_trade.total_income();
+            ledger.record_trade_income(country_entity, country_trade.total_income());
+        }
+    }
+
+    report.countries = trade;
+}
+
+fn trade_panel_ui(
+    mut contexts: EguiContexts,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+    report: Res<TradeReport>,
+    prices: Res<TradePrices>,
+) {
+    let Ok(ctx) = contexts.ctx_mut() else {
+        return;
+    };
+
+    let Some(player_country) = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0)
+    else {
+        return;
+    };
+
+    egui::Window::new("Trade")
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-20.0, -140.0])
+        .resizable(false)
+        .default_open(false)
+        .show(ctx, |ui| {
+            let Some(country_trade) = report.countries.get(&player_country) else {
+                ui.label("No trade has taken place yet");
+                return;
+            };
+
+            egui::Grid::new("trade_grid").striped(true).show(ui, |ui| {
+                ui.strong("Good");
+                ui.strong("Produced");
+                ui.strong("Exported");
+                ui.strong("Imported");
+                ui.strong("Price");
+                ui.strong("Income");
+                ui.end_row();
+
+                for good in ALL_TRADE_GOODS {
+                    let count = |map: &HashMap<TradeGood, u32>| {
+                        map.get(&good).copied().unwrap_or(0).to_string()
+                    };
+
+                    ui.label(good.name());
+                    ui.label(count(&country_trade.produced));
+                    ui.label(count(&country_trade.exported));
+                    ui.label(count(&country_trade.imported));
+                    ui.label(prices.price(good).to_string());
+                    ui.label(count(&country_trade.income));
+                    ui.end_row();
+                }
+            });
+
+            ui.separator();
+            ui.label(
+                egui::RichText::new(format!(
+                    "Trade income last turn: {} gold",
+                    country_trade.total_income()
+                ))
+                .color(egui::Color32::GOLD),
+            );
+        });
+}
@@ -0,0 +1,107 @@
+// plugins/truces.rs
+use crate::components::country::{DiplomacyChanged, Relation};
+use crate::plugins::turn::TurnResolutionSet;
+use crate::plugins::war_score::Wars;
+use bevy::prelude::*;
+
+/// Turns after a peace during which the two countries cannot declare war on each other.
+pub const TRUCE_TURNS: u32 = 10;
+
+pub struct TrucesPlugin;
+
+impl Plugin for TrucesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Truces>()
+            .add_systems(Update, tick_truces.in_set(TurnResolutionSet::End))
+            .add_observer(on_peace_start_truce);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Truce {
+    pub sides: [Entity; 2],
+    pub turns_left: u32,
+}
+
+/// Every truce still running.
+#[derive(Resource, Default)]
+pub struct Truces {
+    pub truces: Vec<Truce>,
+}
+
+impl Truces {
+    /// Turns left on the truce between `a` and `b`, if there is one.
+    pub fn turns_left(&self, a: Entity, b: Entity) -> Option<u32> {
+        self.truces
+            .iter()
+            .find(|truce| truce.sides.contains(&a) && truce.sides.contains(&b))
+            .map(|truce| truce.turns_left)
+    }
+
+    pub fn between(&self, a: Entity, b: Entity) -> bool {
+        self.turns_left(a, b).is_some()
+    }
+
+    /// Starts a truce, or restarts the one already running.
+    pub fn start(&mut self, a: Entity, b: Entity) {
+        self.truces
+            .retain(|truce| !(truce.sides.contains(&a) && truce.sides.contains(&b)));
+        self.truces.push(Truce {
+            sides: [a, b],
+            turns_left: TRUCE_TURNS,
+        });
+    }
+}
+
+/// Peace after a war starts a truce. Wars are still tracked when peace is
+/// made, since `track_wars` only drops them at the end of the turn.
+fn on_peace_start_truce(
+    trigger: On<DiplomacyChanged>,
+    mut truces: ResMut<Truces>,
+    wars: Res<Wars>,
+) {
+    let ev = trigger.event();
+
+    if ev.new_relation == Relation::Peace && wars.get(ev.declarer, ev.target).is_some() {
+        truces.start(ev.declarer, ev.target);
+    }
+}
+
+fn tick_truces(mut truces: ResMut<Truces>) {
+    for truce in &mut truces.truces {
+        truce.turns_left = truce.turns_left.saturating_sub(1);
+    }
+    truces.truces.retain(|truce| truce.turns_left > 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::misc::test_entities;
+
+    #[test]
+    fn truce_binds_both_sides() {
+        let countries = test_entities(3);
+        let (a, b, c) = (countries[0], countries[1], countries[2]);
+        let mut truces = Truces::default();
+        truces.start(a, b);
+
+        assert!(truces.between(a, b));
+        assert!(truces.between(b, a));
+        assert!(!truces.between(a, c));
+        assert_eq!(truces.turns_left(b, a), Some(TRUCE_TURNS));
+    }
+
+    #[test]
+    fn starting_again_restarts_the_truce() {
+        let countries = test_entities(2);
+        let (a, b) = (countries[0], countries[1]);
+        let mut truces = Truces::default();
+        truces.start(a, b);
+        truces.truces[0].turns_left = 1;
+        truces.start(b, a);
+
+        assert_eq!(truces.truces.len(), 1);
+        assert_eq!(truces.turns_left(a, b), Some(TRUCE_TURNS));
+    }
+}
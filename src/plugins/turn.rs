@@ -1,11 +1,33 @@
 use bevy::platform::collections::HashMap;
 // plugins/turn.rs
 use crate::components::army::{Army, HasActedThisTurn, PendingMove};
-use crate::components::buildings::{ALL_BUILDINGS, BuildingType, Buildings};
-use crate::components::country::{AIControlled, Country, DiplomacyChanged, Relation, Relations};
+use crate::components::buildings::{BuildingType, Buildings};
+use crate::components::country::{
+    AIControlled, Country, DEFAULT_TAX_RATE, DiplomacyChanged, RebelFaction, Relation, Relations,
+    VassalOf,
+};
+use crate::components::debt::{Debt, LOAN_AMOUNT};
 use crate::components::events::{GameEvent, PendingEvent};
+use crate::components::opinion::Opinions;
+use crate::components::personality::Personality;
+use crate::components::player::{ControlsCountry, LocalPlayer};
 use crate::components::province::{Occupied, OwnedBy, Province};
+use crate::misc::ai_controller::{
+    AiController, AiOrder, ArmyView, Controller, CountryView, ProvinceView, RECRUIT_COST,
+    RECRUIT_UNITS, WorldView,
+};
+use crate::misc::ai_utility::AiWeights;
+use crate::misc::difficulty::Difficulty;
+use crate::misc::economy::{army_upkeep, province_economy, tribute};
+use crate::misc::standard_ai::StandardAi;
+use crate::plugins::claims::{Claims, UnjustifiedWar, WarGoals};
+use crate::plugins::ledger::{BudgetLedger, ExpenseKind};
+use crate::plugins::military_access::{MilitaryAccess, may_enter};
+use crate::plugins::truces::Truces;
+use crate::plugins::victory::GameStats;
+use crate::plugins::war_score::Wars;
 use crate::states::{AppState, GamePhase};
+use anyhow::Context;
 use bevy::prelude::*;
 use rand::Rng;
 use rand::prelude::IndexedRandom;
@@ -16,7 +38,9 @@ pub enum TurnResolutionSet {
     Movement,
     Combat,
     Occupation,
+    Unrest,
     Economy,
+    Debt,
     Event,
     End,
 }
@@ -25,56 +49,61 @@ pub struct TurnPlugin;
 
 impl Plugin for TurnPlugin {
     fn build(&self, app: &mut App) {
-        app.configure_sets(
-            Update,
-            (
-                TurnResolutionSet::AIDecision,
-                TurnResolutionSet::Movement,
-                TurnResolutionSet::Combat,
-                TurnResolutionSet::Occupation,
-                TurnResolutionSet::Economy,
-                TurnResolutionSet::Event,
-                TurnResolutionSet::End,
+        app.init_resource::<AiWeights>()
+            .init_resource::<Difficulty>()
+            .init_resource::<AiOrders>()
+            .add_systems(OnEnter(AppState::LoadingNewGame), load_ai_weights)
+            .add_systems(OnEnter(AppState::LoadingSavedGame), load_ai_weights)
+            .configure_sets(
+                Update,
+                (
+                    TurnResolutionSet::AIDecision,
+                    TurnResolutionSet::Movement,
+                    TurnResolutionSet::Combat,
+                    TurnResolutionSet::Occupation,
+                    TurnResolutionSet::Unrest,
+                    TurnResolutionSet::Economy,
+                    TurnResolutionSet::Debt,
+                    TurnResolutionSet::Event,
+                    TurnResolutionSet::End,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::InGame).and(in_state(GamePhase::Processing))),
             )
-                .chain()
-                .run_if(in_state(AppState::InGame).and(in_state(GamePhase::Processing))),
-        )
-        .add_systems(
-            Update,
-            (
-                ai_build_buildings,
-                ai_recruit_armies.after(ai_build_buildings),
-                ai_move_armies.after(ai_recruit_armies),
-                // ai_declare_war.after(ai_move_armies),
-                ai_diplomacy.after(ai_move_armies),
+            .add_systems(
+                Update,
+                (
+                    ai_set_tax_rates,
+                    ai_take_loans.after(ai_set_tax_rates),
+                    ai_decide.after(ai_take_loans),
+                    apply_ai_orders.after(ai_decide),
+                )
+                    .in_set(TurnResolutionSet::AIDecision)
+                    .run_if(in_state(AppState::InGame)),
             )
-                .in_set(TurnResolutionSet::AIDecision)
-                .run_if(in_state(AppState::InGame)),
-        )
-        .add_systems(
-            Update,
-            process_turn_moves.in_set(TurnResolutionSet::Movement),
-        )
-        .add_systems(Update, resolve_combat.in_set(TurnResolutionSet::Combat))
-        .add_systems(
-            Update,
-            resolve_occupation.in_set(TurnResolutionSet::Occupation),
-        )
-        .add_systems(Update, process_economy.in_set(TurnResolutionSet::Economy))
-        .add_systems(
-            Update,
-            trigger_random_event.in_set(TurnResolutionSet::Event),
-        )
-        .add_systems(
-            Update,
-            crate::misc::empty_function.in_set(TurnResolutionSet::End),
-        )
-        .add_systems(
-            Update,
-            finish_processing
-                .after(TurnResolutionSet::End)
-                .run_if(in_state(AppState::InGame).and(in_state(GamePhase::Processing))),
-        );
+            .add_systems(
+                Update,
+                process_turn_moves.in_set(TurnResolutionSet::Movement),
+            )
+            .add_systems(Update, resolve_combat.in_set(TurnResolutionSet::Combat))
+            .add_systems(
+                Update,
+                resolve_occupation.in_set(TurnResolutionSet::Occupation),
+            )
+            .add_systems(Update, process_economy.in_set(TurnResolutionSet::Economy))
+            .add_systems(
+                Update,
+                trigger_random_event.in_set(TurnResolutionSet::Event),
+            )
+            // A turn that ends on an event closes once the event is resolved,
+            // so that what it cost is counted with the rest of the turn.
+            .add_systems(OnExit(GamePhase::Event), close_ledger_turn)
+            .add_systems(
+                Update,
+                finish_processing
+                    .after(TurnResolutionSet::End)
+                    .run_if(in_state(AppState::InGame).and(in_state(GamePhase::Processing))),
+            );
     }
 }
 
@@ -89,7 +118,13 @@ fn process_turn_moves(
     }
 }
 
-fn resolve_combat(mut commands: Commands, armies: Query<(Entity, &Army)>) {
+fn resolve_combat(
+    mut commands: Commands,
+    armies: Query<(Entity, &Army)>,
+    relations: Query<&Relations>,
+    mut wars: ResMut<Wars>,
+    mut stats: ResMut<GameStats>,
+) {
     let mut province_armies: HashMap<Entity, Vec<(Entity, Entity, u32)>> = HashMap::new();
 
     for (army_entity, army) in &armies {
@@ -117,21 +152,58 @@ fn resolve_combat(mut commands: Commands, armies: Query<(Entity, &Army)>) {
             continue;
         }
 
+        let relation =
+            |a: Entity, b: Entity| relations.get(a).map_or(Relation::Peace, |rels| rels.get(b));
+
+        // Only owners at war with someone here fight; allies present fight alongside them.
         let mut weakest_owner = None;
         let mut weakest_strength = u32::MAX;
 
-        for (&owner, &(total, _)) in &owner_strength {
-            if total < weakest_strength {
-                weakest_strength = total;
+        for &owner in owner_strength.keys() {
+            let has_enemy = owner_strength
+                .keys()
+                .any(|&other| relation(owner, other) == Relation::War);
+
+            if !has_enemy {
+                continue;
+            }
+
+            let side_strength: u32 = owner_strength
+                .iter()
+                .filter(|&(&other, _)| {
+                    other == owner || relation(owner, other) == Relation::Alliance
+                })
+                .map(|(_, &(total, _))| total)
+                .sum();
+
+            if side_strength < weakest_strength {
+                weakest_strength = side_strength;
                 weakest_owner = Some(owner);
             }
         }
 
-        if let Some(loser_owner) = weakest_owner
-            && let Some((_, losing_armies)) = owner_strength.get(&loser_owner)
-        {
-            for &army_entity in losing_armies {
-                commands.entity(army_entity).despawn();
+        if let Some(loser_owner) = weakest_owner {
+            let losing_owners: Vec<Entity> = owner_strength
+                .keys()
+                .copied()
+                .filter(|&other| {
+                    other == loser_owner || relation(loser_owner, other) == Relation::Alliance
+                })
+                .collect();
+
+            for owner in &losing_owners {
+                for &army_entity in &owner_strength[owner].1 {
+                    commands.entity(army_entity).despawn();
+                }
+            }
+
+            for &winner in owner_strength.keys() {
+                for &loser in &losing_owners {
+                    if relation(winner, loser) == Relation::War {
+                        wars.record_battle(winner, loser);
+                        stats.record_battle(winner, loser);
+                    }
+                }
             }
             println!(
                 "Combat in province {:?}: Owner {:?} lost {} units",
@@ -145,6 +217,7 @@ fn resolve_occupation(
     mut commands: Commands,
     provinces: Query<(Entity, &OwnedBy, Option<&Occupied>)>,
     armies: Query<(Entity, &Army)>,
+    relations: Query<&Relations>,
 ) {
     let mut province_to_armies: HashMap<Entity, Vec<Entity>> = HashMap::new();
 
@@ -183,7 +256,14 @@ fn resolve_occupation(
             continue;
         }
 
-        let occupier = present_owners[0];
+        // Allied and neutral armies pass through without taking the province.
+        let Some(&occupier) = present_owners.iter().find(|&&owner| {
+            relations
+                .get(owner)
+                .is_ok_and(|rels| rels.get(owned_by.owner) == Relation::War)
+        }) else {
+            continue;
+        };
 
         if occupied_opt.is_none_or(|o| o.occupier != occupier) {
             commands.entity(prov_entity).insert(Occupied { occupier });
@@ -194,52 +274,79 @@ fn resolve_occupation(
 fn process_economy(
     mut provinces: Query<(Entity, &mut Province, &OwnedBy, &Buildings)>,
     occupied: Query<&Occupied>,
-    mut countries: Query<(Entity, &mut Country)>,
+    mut countries: Query<(Entity, &mut Country, Option<&VassalOf>, Has<AIControlled>)>,
+    armies: Query<&Army>,
+    mut ledger: ResMut<BudgetLedger>,
+    difficulty: Res<Difficulty>,
 ) {
     let mut income_map: HashMap<Entity, u32> = HashMap::new();
 
+    let tax_rates: HashMap<Entity, f32> = countries
+        .iter()
+        .map(|(entity, country, ..)| (entity, country.tax_rate))
+        .collect();
+    let tax_rate = |country: Entity| tax_rates.get(&country).copied().unwrap_or(DEFAULT_TAX_RATE);
+    let culture = |country: Entity| countries.get(country).ok().and_then(|(_, c, ..)| c.culture);
+
     for (prov_entity, mut province, owned_by, buildings) in &mut provinces {
-        let owner = if let Ok(occ) = occupied.get(prov_entity) {
-            occ.occupier
-        } else {
-            owned_by.owner
-        };
+        let occupier = occupied.get(prov_entity).ok().map(|occ| occ.occupier);
+        let collector = occupier.unwrap_or(owned_by.owner);
+
+        let economy = province_economy(
+            &province,
+            buildings,
+            tax_rate(owned_by.owner),
+            occupier.map(tax_rate),
+            culture(owned_by.owner),
+        );
 
-        let mut province_growth = province.base_growth;
+        province.population = economy.new_population;
 
-        for &building in &buildings.built {
-            province_growth += building.growth_bonus();
-        }
-
-        let is_occupied = occupied.get(prov_entity).is_ok();
-        if is_occupied {
-            province_growth = -0.05;
-        }
+        ledger.record_province_income(collector, province.id, economy.income);
+        *income_map.entry(collector).or_insert(0) += economy.income;
+    }
 
-        let growth_amount = (province.population as f32 * province_growth).round() as i32;
+    // Vassals hand a share of their income to their overlord.
+    let vassals: Vec<(Entity, Entity)> = countries
+        .iter()
+        .filter_map(|(entity, _, vassal_of, _)| vassal_of.map(|v| (entity, v.overlord)))
+        .collect();
+    for (vassal, overlord) in vassals {
+        let Some(income) = income_map.get_mut(&vassal) else {
+            continue;
+        };
+        let tribute = tribute(*income);
+        *income -= tribute;
+        *income_map.entry(overlord).or_insert(0) += tribute;
 
-        province.population = (province.population as i32 + growth_amount).max(0) as u32;
+        ledger.record_expense(vassal, ExpenseKind::Tribute, tribute);
+        ledger.record_tribute_income(overlord, tribute);
+    }
 
-        let mut province_income = province.base_income;
+    let mut units_map: HashMap<Entity, u32> = HashMap::new();
+    for army in &armies {
+        *units_map.entry(army.owner).or_insert(0) += army.units;
+    }
 
-        for &building in &buildings.built {
-            province_income += building.income_bonus();
+    for (country_entity, mut country, _, is_ai) in &mut countries {
+        if let Some(&income) = income_map.get(&country_entity) {
+            country.gold += if is_ai {
+                difficulty.ai_income(income)
+            } else {
+                income
+            };
         }
 
-        province_income += province.population / 1000;
-
-        if is_occupied {
-            province_income = (province_income as f32 * 0.5) as u32;
+        if let Some(&units) = units_map.get(&country_entity) {
+            let upkeep = army_upkeep(units).min(country.gold);
+            country.gold -= upkeep;
+            ledger.record_expense(country_entity, ExpenseKind::Upkeep, upkeep);
         }
-
-        *income_map.entry(owner).or_insert(0) += province_income;
     }
+}
 
-    for (country_entity, mut country) in &mut countries {
-        if let Some(income) = income_map.get(&country_entity) {
-            country.gold += *income;
-        }
-    }
+fn close_ledger_turn(mut ledger: ResMut<BudgetLedger>) {
+    ledger.close_turn();
 }
 
 fn finish_processing(
@@ -247,12 +354,15 @@ fn finish_processing(
     pending_moves_q: Query<Entity, With<PendingMove>>,
     mut next_state: ResMut<NextState<GamePhase>>,
     pending_event: Option<Res<PendingEvent>>,
+    mut ledger: ResMut<BudgetLedger>,
 ) {
     if pending_event.is_some() {
         // println!("Event pending, delaying turn finish");
         return;
     }
 
+    ledger.close_turn();
+
     for entity in &pending_moves_q {
         commands.entity(entity).remove::<PendingMove>();
         commands.entity(entity).remove::<HasActedThisTurn>();
@@ -261,288 +371,320 @@ fn finish_processing(
     next_state.set(GamePhase::PlayerTurn);
 }
 
-fn ai_build_buildings(
-    mut ai_countries: Query<(Entity, &mut Country), With<AIControlled>>,
-    mut provinces: Query<(Entity, &OwnedBy, &mut Buildings)>,
-) {
-    let mut rng = rand::rng();
-
-    for (country_entity, mut country) in &mut ai_countries {
-        let affordable: Vec<BuildingType> = ALL_BUILDINGS
-            .into_iter()
-            .filter(|&b| country.gold >= b.cost())
-            .collect();
+fn ai_set_tax_rates(mut ai_countries: Query<(&mut Country, &Relations), With<AIControlled>>) {
+    for (mut country, relations) in &mut ai_countries {
+        let at_war = relations.relations.values().any(|&r| r == Relation::War);
 
-        if affordable.is_empty() {
-            continue;
-        }
-
-        let Some(choice) = affordable.choose(&mut rng).copied() else {
-            continue;
+        let tax_rate = if at_war {
+            0.35
+        } else if country.gold < 500 {
+            0.3
+        } else if country.gold > 3000 {
+            0.15
+        } else {
+            DEFAULT_TAX_RATE
         };
 
-        let candidates: Vec<Entity> = provinces
-            .iter()
-            .filter(|(_, owned_by, buildings)| {
-                owned_by.owner == country_entity && !buildings.built.contains(&choice)
-            })
-            .map(|(e, _, _)| e)
-            .collect();
-
-        if candidates.is_empty() {
-            continue;
-        }
-
-        let chosen_prov = candidates[rng.random_range(0..candidates.len())];
-
-        if let Ok(mut buildings) = provinces.get_mut(chosen_prov).map(|(_, _, b)| b) {
-            buildings.built.push(choice);
-            country.gold -= choice.cost();
-        }
+        country.tax_rate = tax_rate;
     }
 }
 
-fn ai_recruit_armies(
-    mut commands: Commands,
-    mut ai_countries: Query<(Entity, &mut Country), With<AIControlled>>,
-    provinces: Query<(Entity, &Province, &OwnedBy, &Buildings)>, // ← add &Province here
+fn ai_take_loans(
+    mut ai_countries: Query<(Entity, &mut Country, &mut Debt, &Relations), With<AIControlled>>,
+    mut ledger: ResMut<BudgetLedger>,
 ) {
-    let mut rng = rand::rng();
+    for (country_entity, mut country, mut debt, relations) in &mut ai_countries {
+        let at_war = relations.relations.values().any(|&r| r == Relation::War);
 
-    for (country_entity, mut country) in &mut ai_countries {
-        if country.gold < 100 {
+        if !at_war || country.gold >= LOAN_AMOUNT || !debt.can_borrow() {
             continue;
         }
 
-        let barracks_provinces: Vec<(Entity, &Province)> = provinces
-            .iter()
-            .filter(|(_, _, owned_by, buildings)| {
-                owned_by.owner == country_entity
-                    && buildings.built.contains(&BuildingType::Barracks)
-            })
-            .map(|(e, prov, _, _)| (e, prov))
-            .collect();
+        debt.take_loan(LOAN_AMOUNT);
+        country.gold += LOAN_AMOUNT;
+        ledger.record_borrowing(country_entity, LOAN_AMOUNT);
 
-        if barracks_provinces.is_empty() {
-            continue;
-        }
-
-        let (prov_entity, province) =
-            barracks_provinces[rng.random_range(0..barracks_provinces.len())];
-
-        commands.spawn((
-            Army {
-                owner: country_entity,
-                province: prov_entity,
-                units: 100,
-            },
-            Transform::from_xyz(province.center.x, 0.0, province.center.y),
-            GlobalTransform::default(),
-            Visibility::Visible,
-            InheritedVisibility::default(),
-            ViewVisibility::default(),
-        ));
-
-        country.gold -= 100;
+        println!("AI country {} took a loan to fund its war", country.name);
     }
 }
 
-fn ai_move_armies(
-    mut commands: Commands,
-    ai_countries: Query<Entity, With<AIControlled>>,
-    armies: Query<(Entity, &Army)>,
-    provinces: Query<(Entity, &Province, &OwnedBy)>,
-    relations: Query<&Relations>,
-    pending_moves: Query<&PendingMove>,
-) {
-    let mut rng = rand::rng();
-
-    for country_entity in &ai_countries {
-        let my_armies: Vec<(Entity, &Army)> = armies
-            .iter()
-            .filter(|(_, army)| army.owner == country_entity)
-            .collect();
-
-        for (army_entity, army) in my_armies {
-            if !rng.random_bool(0.2) {
-                continue;
-            }
-
-            let Ok((_, current_prov, _)) = provinces.get(army.province) else {
-                continue;
-            };
-
-            let all_targets: Vec<(Entity, Entity)> = current_prov
-                .neighbors
-                .iter()
-                .filter_map(|&nid| {
-                    provinces
-                        .iter()
-                        .find(|(_e, p, _o)| p.id == nid)
-                        .map(|(e, _, o)| (e, o.owner))
-                })
-                .collect();
-
-            if all_targets.is_empty() {
-                continue;
-            }
-
-            let enemy_targets: Vec<Entity> = all_targets
-                .iter()
-                .filter(|(_, owner)| *owner != country_entity)
-                .filter(|(_, owner)| {
-                    relations
-                        .get(country_entity)
-                        .is_ok_and(|r| r.get(*owner) == Relation::War)
-                })
-                .map(|(e, _)| *e)
-                .collect();
-
-            let friendly_targets: Vec<Entity> = all_targets
-                .iter()
-                .filter(|(_, owner)| *owner == country_entity)
-                .map(|(e, _)| *e)
-                .collect();
-
-            let target_province = if !enemy_targets.is_empty() {
-                if rng.random_bool(0.9) {
-                    enemy_targets[rng.random_range(0..enemy_targets.len())]
-                } else if !friendly_targets.is_empty() {
-                    friendly_targets[rng.random_range(0..friendly_targets.len())]
-                } else {
-                    continue;
-                }
-            } else if !friendly_targets.is_empty() {
-                friendly_targets[rng.random_range(0..friendly_targets.len())]
-            } else {
-                continue;
-            };
-
-            if pending_moves
-                .get(army_entity)
-                .is_ok_and(|p| p.target_province == target_province)
-            {
-                continue;
-            }
-
-            commands
-                .entity(army_entity)
-                .insert(PendingMove { target_province });
+fn load_ai_weights(mut commands: Commands) {
+    match load_ai_weights_from_file() {
+        Ok(weights) => commands.insert_resource(weights),
+        Err(err) => {
+            eprintln!("{:?}", err);
+            commands.insert_resource(AiWeights::default());
         }
     }
 }
 
-fn ai_diplomacy(
-    commands: Commands,
-    ai_countries: Query<(Entity, &Country), With<AIControlled>>,
-    relations: Query<&mut Relations>,
-    countries: Query<Entity, With<Country>>,
-) {
-    let r = rand::rng().random_range(0.0..1.0);
-    if r < 0.2 {
-        ai_declare_war(commands, ai_countries, relations, countries);
-    } else if r < 0.4 {
-        ai_propose_peace(commands, ai_countries, relations, countries);
-    }
+fn load_ai_weights_from_file() -> anyhow::Result<AiWeights> {
+    let file = std::fs::read_to_string("assets/data/ai_weights.ron")?;
+    ron::from_str(&file).context("Failed to parse ai_weights.ron")
 }
 
-fn ai_declare_war(
-    mut commands: Commands,
-    ai_countries: Query<(Entity, &Country), With<AIControlled>>,
-    mut relations: Query<&mut Relations>,
-    countries: Query<Entity, With<Country>>,
+/// Orders the AI countries gave this turn, waiting to be carried out.
+#[derive(Resource, Default)]
+struct AiOrders(Vec<(Entity, Vec<AiOrder>)>);
+
+/// Shows every AI country the game from its side and collects the orders of
+/// its controller, see misc/ai_controller.rs.
+fn ai_decide(
+    ai_countries: Query<
+        (
+            Entity,
+            &Country,
+            &Relations,
+            &Opinions,
+            Option<&Personality>,
+            Option<&Controller>,
+            Has<VassalOf>,
+        ),
+        With<AIControlled>,
+    >,
+    provinces: Query<(Entity, &Province, &OwnedBy, &Buildings, Option<&Occupied>)>,
+    armies: Query<(Entity, &Army)>,
+    countries: Query<Entity, (With<Country>, Without<RebelFaction>)>,
+    (access, truces, weights, difficulty): (
+        Res<MilitaryAccess>,
+        Res<Truces>,
+        Res<AiWeights>,
+        Res<Difficulty>,
+    ),
+    war_goals: WarGoals,
+    mut orders: ResMut<AiOrders>,
 ) {
-    let mut rng = rand::rng();
+    let entities_by_id: HashMap<u32, Entity> = provinces
+        .iter()
+        .map(|(entity, province, ..)| (province.id, entity))
+        .collect();
 
-    for (country_entity, _) in &ai_countries {
-        let possible_targets: Vec<Entity> = countries
+    let world = WorldView {
+        provinces: provinces
             .iter()
-            .filter(|&e| e != country_entity)
-            .filter(|&e| {
-                relations
-                    .get(country_entity)
-                    .map_or(true, |r| r.get(e) == Relation::Peace)
+            .map(|(entity, province, owned_by, buildings, occupied)| {
+                let view = ProvinceView {
+                    province,
+                    buildings,
+                    owner: owned_by.owner,
+                    occupier: occupied.map(|occ| occ.occupier),
+                    neighbors: province
+                        .neighbors
+                        .iter()
+                        .filter_map(|id| entities_by_id.get(id).copied())
+                        .collect(),
+                };
+                (entity, view)
             })
-            .collect();
-
-        if possible_targets.is_empty() {
-            continue;
-        }
-
-        let target = possible_targets[rng.random_range(0..possible_targets.len())];
-
-        if let Ok(mut my_rels) = relations.get_mut(country_entity) {
-            my_rels.set(target, Relation::War);
-        }
-
-        if let Ok(mut target_rels) = relations.get_mut(target) {
-            target_rels.set(country_entity, Relation::War);
-        }
-
-        commands.trigger(DiplomacyChanged {
-            declarer: country_entity,
-            target,
-            new_relation: Relation::War,
-        });
+            .collect(),
+        armies: armies
+            .iter()
+            .map(|(entity, army)| ArmyView {
+                entity,
+                owner: army.owner,
+                province: army.province,
+                units: army.units,
+            })
+            .collect(),
+        countries: countries.iter().collect(),
+        weights: &weights,
+        difficulty: *difficulty,
+    };
+
+    for (country_entity, country, relations, opinions, personality, controller, is_vassal) in
+        &ai_countries
+    {
+        let others = world
+            .countries
+            .iter()
+            .copied()
+            .filter(|&other| other != country_entity);
+
+        let view = CountryView {
+            country: country_entity,
+            gold: country.gold,
+            tax_rate: country.tax_rate,
+            culture: country.culture,
+            personality: personality.copied().unwrap_or_default(),
+            relations,
+            opinions,
+            is_vassal,
+            access_from: others
+                .clone()
+                .filter(|&other| may_enter(relations, &access, country_entity, other))
+                .collect(),
+            truces: others
+                .clone()
+                .filter(|&other| truces.between(country_entity, other))
+                .collect(),
+            casus_belli: others
+                .filter_map(|other| {
+                    war_goals
+                        .casus_belli(country_entity, other)
+                        .map(|casus_belli| (other, casus_belli))
+                })
+                .collect(),
+            world: &world,
+        };
 
-        println!(
-            "AI country {:?} declared war on {:?}",
-            country_entity, target
-        );
+        let controller: &dyn AiController = controller
+            .map_or(&StandardAi as &dyn AiController, |controller| {
+                controller.0.as_ref()
+            });
+        orders.0.push((country_entity, controller.decide(&view)));
     }
 }
 
-fn ai_propose_peace(
+/// Carries out the orders the AI countries gave, dropping any they cannot
+/// afford or are not allowed to give.
+fn apply_ai_orders(
     mut commands: Commands,
-    ai_countries: Query<(Entity, &Country), With<AIControlled>>,
-    mut relations: Query<&mut Relations>,
-    countries: Query<Entity, With<Country>>,
+    mut orders: ResMut<AiOrders>,
+    mut countries: Query<(&mut Country, &mut Relations, Has<VassalOf>)>,
+    mut provinces: Query<(&Province, &OwnedBy, &mut Buildings)>,
+    (armies, pending_moves): (Query<&Army>, Query<&PendingMove>),
+    (mut ledger, difficulty, access, truces): (
+        ResMut<BudgetLedger>,
+        Res<Difficulty>,
+        Res<MilitaryAccess>,
+        Res<Truces>,
+    ),
+    war_goals: WarGoals,
 ) {
-    println!("ai_propose_peace");
-    let mut rng = rand::rng();
-
-    for (country_entity, _) in &ai_countries {
-        let possible_targets: Vec<Entity> = countries
-            .iter()
-            .filter(|&e| e != country_entity)
-            .filter(|&e| {
-                relations
-                    .get(country_entity)
-                    .is_ok_and(|r| r.get(e) == Relation::War)
-            })
-            .collect();
-
-        if possible_targets.is_empty() {
-            continue;
-        }
-
-        let target = possible_targets[rng.random_range(0..possible_targets.len())];
-
-        if let Ok(mut my_rels) = relations.get_mut(country_entity) {
-            my_rels.set(target, Relation::Peace);
-        }
-
-        if let Ok(mut target_rels) = relations.get_mut(target) {
-            target_rels.set(country_entity, Relation::Peace);
+    for (country_entity, country_orders) in std::mem::take(&mut orders.0) {
+        for order in country_orders {
+            match order {
+                AiOrder::Build { province, building } => {
+                    let cost = difficulty.ai_building_cost(building.cost());
+                    let Ok((mut country, ..)) = countries.get_mut(country_entity) else {
+                        continue;
+                    };
+                    let Ok((_, owned_by, mut buildings)) = provinces.get_mut(province) else {
+                        continue;
+                    };
+                    if owned_by.owner != country_entity
+                        || buildings.built.contains(&building)
+                        || country.gold < cost
+                    {
+                        continue;
+                    }
+
+                    buildings.built.push(building);
+                    country.gold -= cost;
+                    ledger.record_expense(country_entity, ExpenseKind::Building, cost);
+                }
+                AiOrder::Recruit { province } => {
+                    let Ok((mut country, ..)) = countries.get_mut(country_entity) else {
+                        continue;
+                    };
+                    let Ok((province_data, owned_by, buildings)) = provinces.get(province) else {
+                        continue;
+                    };
+                    if owned_by.owner != country_entity
+                        || !buildings.built.contains(&BuildingType::Barracks)
+                        || country.gold < RECRUIT_COST
+                    {
+                        continue;
+                    }
+
+                    commands.spawn((
+                        Army {
+                            owner: country_entity,
+                            province,
+                            units: RECRUIT_UNITS,
+                        },
+                        Transform::from_xyz(province_data.center.x, 0.0, province_data.center.y),
+                        GlobalTransform::default(),
+                        Visibility::Visible,
+                        InheritedVisibility::default(),
+                        ViewVisibility::default(),
+                    ));
+
+                    country.gold -= RECRUIT_COST;
+                    ledger.record_expense(country_entity, ExpenseKind::Recruiting, RECRUIT_COST);
+                }
+                AiOrder::Move { army, province } => {
+                    let Ok(army_data) = armies.get(army) else {
+                        continue;
+                    };
+                    let (Ok((current, ..)), Ok((target, owned_by, _))) =
+                        (provinces.get(army_data.province), provinces.get(province))
+                    else {
+                        continue;
+                    };
+
+                    let open = countries
+                        .get(country_entity)
+                        .is_ok_and(|(_, relations, _)| {
+                            may_enter(relations, &access, country_entity, owned_by.owner)
+                        });
+                    if army_data.owner != country_entity
+                        || !current.neighbors.contains(&target.id)
+                        || !open
+                        || pending_moves
+                            .get(army)
+                            .is_ok_and(|p| p.target_province == province)
+                    {
+                        continue;
+                    }
+
+                    commands.entity(army).insert(PendingMove {
+                        target_province: province,
+                    });
+                }
+                AiOrder::DeclareWar { target } => {
+                    if target == country_entity || truces.between(country_entity, target) {
+                        continue;
+                    }
+                    let Ok(
+                        [
+                            (declarer, mut declarer_relations, is_vassal),
+                            (target_country, mut target_relations, _),
+                        ],
+                    ) = countries.get_many_mut([country_entity, target])
+                    else {
+                        continue;
+                    };
+                    if is_vassal || declarer_relations.get(target) != Relation::Peace {
+                        continue;
+                    }
+
+                    declarer_relations.set(target, Relation::War);
+                    target_relations.set(country_entity, Relation::War);
+
+                    commands.trigger(DiplomacyChanged {
+                        declarer: country_entity,
+                        target,
+                        new_relation: Relation::War,
+                    });
+                    if war_goals.casus_belli(country_entity, target).is_none() {
+                        commands.trigger(UnjustifiedWar {
+                            declarer: country_entity,
+                            target,
+                        });
+                    }
+
+                    println!(
+                        "AI country {} declared war on {}",
+                        declarer.name, target_country.name
+                    );
+                }
+            }
         }
-
-        commands.trigger(DiplomacyChanged {
-            declarer: country_entity,
-            target,
-            new_relation: Relation::Peace,
-        });
-
-        println!(
-            "AI country {:?} made peace with {:?}",
-            country_entity, target
-        );
     }
 }
 
+/// Chance each turn that the player discovers a claim on foreign land.
+const CLAIM_EVENT_CHANCE: f64 = 0.05;
+
 fn trigger_random_event(
     mut commands: Commands,
     mut next_state: ResMut<NextState<GamePhase>>,
     pending_event: Option<Res<PendingEvent>>,
+    local_player: Option<Res<LocalPlayer>>,
+    player_query: Query<&ControlsCountry>,
+    countries: Query<(&Country, Has<RebelFaction>)>,
+    (provinces, claims): (Query<(Entity, &Province, &OwnedBy)>, Res<Claims>),
 ) {
     // println!("trigger_random_event");
     if pending_event.is_some() {
@@ -551,8 +693,42 @@ fn trigger_random_event(
 
     let mut rng = rand::rng();
 
+    let player_country = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0);
+
+    // Now and then old charters turn up, giving the player a claim on foreign land.
+    if let Some(player_country) = player_country
+        && rng.random_bool(CLAIM_EVENT_CHANCE)
+    {
+        let foreign: Vec<(Entity, u32, Entity)> = provinces
+            .iter()
+            .filter(|(entity, _, owned_by)| {
+                owned_by.owner != player_country
+                    && !claims.has(player_country, *entity)
+                    && countries
+                        .get(owned_by.owner)
+                        .is_ok_and(|(_, is_rebel)| !is_rebel)
+            })
+            .map(|(entity, province, owned_by)| (entity, province.id, owned_by.owner))
+            .collect();
+
+        if let Some(&(province, province_id, owner)) = foreign.choose(&mut rng)
+            && let Ok((owner_country, _)) = countries.get(owner)
+        {
+            let event = GameEvent::claim_discovered(province, province_id, &owner_country.name);
+            commands.insert_resource(PendingEvent::new(event));
+            next_state.set(GamePhase::Event);
+            return;
+        }
+    }
+
     if rng.random_bool(0.5) {
-        let event = GameEvent::generate_random();
+        let tax_revolt_chance = player_country
+            .and_then(|country| countries.get(country).ok())
+            .map_or(0.5, |(country, _)| country.tax_revolt_chance());
+
+        let event = GameEvent::generate_random(tax_revolt_chance);
         commands.insert_resource(PendingEvent::new(event));
         next_state.set(GamePhase::Event);
         println!("Event triggered!");
@@ -0,0 +1,287 @@
+// plugins/unrest.rs
+use crate::components::army::{Army, PendingMove};
+use crate::components::country::{
+    AIControlled, Country, DEFAULT_TAX_RATE, Grievances, RebelFaction, Relation, Relations,
+};
+use crate::components::culture::{CultureMatch, religion_differs};
+use crate::components::debt::Debt;
+use crate::components::opinion::Opinions;
+use crate::components::personality::Personality;
+use crate::components::province::{Core, HeldByRebels, Occupied, OwnedBy, Province};
+use crate::misc::unrest::{
+    REBEL_VICTORY_TURNS, UNREST_AFTER_UPRISING, apply_unrest_change, rebel_units, unrest_change,
+    uprising_chance,
+};
+use crate::plugins::turn::TurnResolutionSet;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use rand::Rng;
+use rand::prelude::IndexedRandom;
+
+pub struct UnrestPlugin;
+
+impl Plugin for UnrestPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (update_unrest, spawn_rebel_uprisings, rebel_independence)
+                .chain()
+                .in_set(TurnResolutionSet::Unrest),
+        )
+        .add_systems(
+            Update,
+            move_rebel_armies.in_set(TurnResolutionSet::AIDecision),
+        );
+    }
+}
+
+fn update_unrest(
+    mut provinces: Query<(Entity, &mut Province, &OwnedBy, Option<&Core>)>,
+    occupied: Query<&Occupied>,
+    countries: Query<&Country>,
+) {
+    for (prov_entity, mut province, owned_by, core) in &mut provinces {
+        let owner = countries.get(owned_by.owner).ok();
+        let tax_rate = owner.map_or(DEFAULT_TAX_RATE, |c| c.tax_rate);
+        let is_foreign = core.is_some_and(|c| c.country != owned_by.owner);
+
+        let change = unrest_change(
+            occupied.contains(prov_entity),
+            is_foreign,
+            tax_rate,
+            CultureMatch::between(province.culture, owner.and_then(|c| c.culture)),
+            religion_differs(province.religion, owner.and_then(|c| c.religion)),
+        );
+        province.unrest = apply_unrest_change(province.unrest, change.total);
+    }
+}
+
+fn spawn_rebel_uprisings(
+    mut commands: Commands,
+    mut provinces: Query<(Entity, &mut Province), With<OwnedBy>>,
+    rebels: Query<Entity, With<RebelFaction>>,
+) {
+    let Ok(rebel_faction) = rebels.single() else {
+        return;
+    };
+
+    let mut rng = rand::rng();
+
+    for (prov_entity, mut province) in &mut provinces {
+        if !rng.random_bool(uprising_chance(province.unrest)) {
+            continue;
+        }
+
+        let units = rebel_units(province.population);
+
+        commands.spawn((
+            Army {
+                owner: rebel_faction,
+                province: prov_entity,
+                units,
+            },
+            Transform::from_xyz(province.center.x, 0.0, province.center.y),
+            GlobalTransform::default(),
+            Visibility::Visible,
+            InheritedVisibility::default(),
+            ViewVisibility::default(),
+        ));
+
+        province.unrest = UNREST_AFTER_UPRISING;
+
+        println!(
+            "Rebels rose up in province {} with {} units",
+            province.id, units
+        );
+    }
+}
+
+type RebelProvinceQuery<'a> = (
+    Entity,
+    &'a Province,
+    &'a mut OwnedBy,
+    Option<&'a Occupied>,
+    Option<&'a mut HeldByRebels>,
+);
+
+fn rebel_independence(
+    mut commands: Commands,
+    mut provinces: Query<RebelProvinceQuery>,
+    countries: Query<&Country>,
+    mut rebels: Query<(Entity, &mut Relations), With<RebelFaction>>,
+    armies: Query<(Entity, &Army)>,
+) {
+    let Ok((rebel_faction, mut rebel_relations)) = rebels.single_mut() else {
+        return;
+    };
+
+    let mut breakaways: HashMap<Entity, Vec<Entity>> = HashMap::new();
+
+    for (prov_entity, _, owned_by, occupied, held) in &mut provinces {
+        let rebel_held = occupied.is_some_and(|occ| occ.occupier == rebel_faction);
+
+        match (rebel_held, held) {
+            (false, Some(_)) => {
+                commands.entity(prov_entity).remove::<HeldByRebels>();
+            }
+            (false, None) => {}
+            (true, None) => {
+                commands
+                    .entity(prov_entity)
+                    .insert(HeldByRebels { turns: 1 });
+            }
+            (true, Some(mut held)) => {
+                held.turns += 1;
+                if held.turns >= REBEL_VICTORY_TURNS {
+                    breakaways
+                        .entry(owned_by.owner)
+                        .or_default()
+                        .push(prov_entity);
+                }
+            }
+        }
+    }
+
+    let mut next_id = countries.iter().map(|c| c.id).max().unwrap_or(0) + 1;
+
+    for (old_owner, province_entities) in breakaways {
+        let (name, color) = countries.get(old_owner).map_or(
+            ("Free State".to_string(), Color::srgb(0.6, 0.6, 0.6)),
+            |c| (format!("Free {}", c.name), breakaway_color(c.color)),
+        );
+
+        let province_ids: Vec<u32> = province_entities
+            .iter()
+            .filter_map(|&e| provinces.get(e).ok().map(|(_, p, ..)| p.id))
+            .collect();
+
+        // The breakaway country takes on the identity of the people who rose up.
+        let (culture, religion) = province_entities
+            .first()
+            .and_then(|&e| provinces.get(e).ok())
+            .map_or((None, None), |(_, p, ..)| (p.culture, p.religion));
+
+        let mut relations = Relations::default();
+        relations.set(rebel_faction, Relation::War);
+
+        let new_country = commands
+            .spawn((
+                Country {
+                    id: next_id,
+                    name: name.clone(),
+                    color,
+                    owned_provinces: province_ids,
+                    gold: 0,
+                    tax_rate: DEFAULT_TAX_RATE,
+                    culture,
+                    religion,
+                    flag: None,
+                    flag_path: None,
+                },
+                relations,
+                Debt::default(),
+                Grievances::default(),
+                Opinions::default(),
+                Personality::default(),
+                AIControlled,
+            ))
+            .id();
+        next_id += 1;
+
+        rebel_relations.set(new_country, Relation::War);
+
+        for &prov_entity in &province_entities {
+            if let Ok((_, _, mut owned_by, _, _)) = provinces.get_mut(prov_entity) {
+                owned_by.owner = new_country;
+            }
+
+            commands
+                .entity(prov_entity)
+                .remove::<(Occupied, HeldByRebels)>()
+                .insert(Core {
+                    country: new_country,
+                });
+        }
+
+        // The rebel armies that won independence become the new country's army.
+        for (army_entity, army) in &armies {
+            if army.owner != rebel_faction || !province_entities.contains(&army.province) {
+                continue;
+            }
+
+            commands.entity(army_entity).despawn();
+
+            if let Ok((_, province, ..)) = provinces.get(army.province) {
+                commands.spawn((
+                    Army {
+                        owner: new_country,
+                        province: army.province,
+                        units: army.units,
+                    },
+                    Transform::from_xyz(province.center.x, 0.0, province.center.y),
+                    GlobalTransform::default(),
+                    Visibility::Visible,
+                    InheritedVisibility::default(),
+                    ViewVisibility::default(),
+                ));
+            }
+        }
+
+        println!("Rebels won independence: {} was founded", name);
+    }
+}
+
+fn breakaway_color(color: Color) -> Color {
+    match color {
+        Color::Srgba(s) => Color::srgb(
+            (s.red * 0.6 + 0.3).clamp(0.0, 1.0),
+            (s.green * 0.6 + 0.3).clamp(0.0, 1.0),
+            (s.blue * 0.6 + 0.3).clamp(0.0, 1.0),
+        ),
+        _ => color,
+    }
+}
+
+fn move_rebel_armies(
+    mut commands: Commands,
+    armies: Query<(Entity, &Army)>,
+    rebels: Query<Entity, With<RebelFaction>>,
+    provinces: Query<(Entity, &Province, Option<&Occupied>), With<OwnedBy>>,
+) {
+    let Ok(rebel_faction) = rebels.single() else {
+        return;
+    };
+
+    let mut rng = rand::rng();
+
+    for (army_entity, army) in &armies {
+        if army.owner != rebel_faction {
+            continue;
+        }
+
+        let Ok((_, current_prov, current_occupied)) = provinces.get(army.province) else {
+            continue;
+        };
+
+        // Rebels hold on to the province they rose in until they control it.
+        if current_occupied.is_none_or(|occ| occ.occupier != rebel_faction) || !rng.random_bool(0.3)
+        {
+            continue;
+        }
+
+        let targets: Vec<Entity> = provinces
+            .iter()
+            .filter(|(_, p, occupied)| {
+                current_prov.neighbors.contains(&p.id)
+                    && occupied.is_none_or(|occ| occ.occupier != rebel_faction)
+            })
+            .map(|(e, _, _)| e)
+            .collect();
+
+        if let Some(&target_province) = targets.choose(&mut rng) {
+            commands
+                .entity(army_entity)
+                .insert(PendingMove { target_province });
+        }
+    }
+}
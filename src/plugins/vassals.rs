@@ -0,0 +1,353 @@
+// plugins/vassals.rs
+use crate::components::army::Army;
+use crate::components::country::{
+    AIControlled, Country, DiplomacyChanged, RebelFaction, Relation, Relations, VassalOf,
+};
+use crate::components::opinion::Opinions;
+use crate::components::province::{OwnedBy, Province};
+use crate::plugins::peace::province_value;
+use crate::plugins::proposals::{ProposalAnswered, ProposalKind, Proposals};
+use crate::plugins::turn::TurnResolutionSet;
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashSet;
+use bevy::prelude::*;
+use rand::Rng;
+use rand::prelude::IndexedRandom;
+
+/// Army units worth one point of strength; a province is worth its value.
+const UNITS_PER_STRENGTH: u32 = 20;
+/// A country accepts vassalage from one at least this many times stronger.
+const VASSALAGE_STRENGTH_RATIO: i32 = 3;
+/// A vassal this strong compared to its overlord starts looking for independence.
+const INDEPENDENCE_STRENGTH_RATIO: f32 = 0.75;
+
+pub struct VassalsPlugin;
+
+impl Plugin for VassalsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                ai_answer_vassalage_proposals,
+                ai_offer_vassalage,
+                ai_seek_independence,
+            )
+                .chain()
+                .in_set(TurnResolutionSet::AIDecision),
+        )
+        .add_observer(on_war_call_vassals)
+        .add_observer(on_vassalage_proposal_answered);
+    }
+}
+
+/// Land and armies, to compare how strong countries are.
+#[derive(SystemParam)]
+pub struct CountryStrength<'w, 's> {
+    provinces: Query<'w, 's, (&'static Province, &'static OwnedBy)>,
+    armies: Query<'w, 's, &'static Army>,
+}
+
+impl CountryStrength<'_, '_> {
+    pub fn of(&self, country: Entity) -> i32 {
+        let land: i32 = self
+            .provinces
+            .iter()
+            .filter(|(_, owned_by)| owned_by.owner == country)
+            .map(|(province, _)| province_value(province))
+            .sum();
+        let units: u32 = self
+            .armies
+            .iter()
+            .filter(|army| army.owner == country)
+            .map(|army| army.units)
+            .sum();
+
+        land + (units / UNITS_PER_STRENGTH) as i32
+    }
+}
+
+/// Whether a country of `strength` that thinks `opinion` of the proposer agrees
+/// to become the vassal of a country of `proposer_strength`.
+pub fn accepts_vassalage(strength: i32, proposer_strength: i32, opinion: f32) -> bool {
+    opinion >= 0.0 && overshadows(proposer_strength, strength)
+}
+
+/// Whether a country of `strength` is strong enough to take one of `other_strength` as a vassal.
+fn overshadows(strength: i32, other_strength: i32) -> bool {
+    strength >= other_strength * VASSALAGE_STRENGTH_RATIO
+}
+
+/// Whether `country` is a vassal or has vassals of its own; either way it
+/// cannot be made a vassal.
+pub fn is_bound(vassals: &Query<&VassalOf>, country: Entity) -> bool {
+    vassals.contains(country) || vassals.iter().any(|v| v.overlord == country)
+}
+
+/// Makes `vassal` a subject of `overlord`. The two become allies so their
+/// armies march together.
+pub fn make_vassal(
+    commands: &mut Commands,
+    vassal: (Entity, &mut Relations),
+    overlord: (Entity, &mut Relations),
+) {
+    let (vassal, vassal_relations) = vassal;
+    let (overlord, overlord_relations) = overlord;
+
+    commands.entity(vassal).insert(VassalOf { overlord });
+    vassal_relations.set(overlord, Relation::Alliance);
+    overlord_relations.set(vassal, Relation::Alliance);
+
+    commands.trigger(DiplomacyChanged {
+        declarer: overlord,
+        target: vassal,
+        new_relation: Relation::Alliance,
+    });
+}
+
+/// Lets `vassal` go in peace; it stays on good terms but is no longer bound.
+pub fn release_vassal(
+    commands: &mut Commands,
+    vassal: (Entity, &mut Relations),
+    overlord: (Entity, &mut Relations),
+) {
+    let (vassal, vassal_relations) = vassal;
+    let (overlord, overlord_relations) = overlord;
+
+    commands.entity(vassal).remove::<VassalOf>();
+    vassal_relations.set(overlord, Relation::Peace);
+    overlord_relations.set(vassal, Relation::Peace);
+}
+
+/// Vassals fight their overlord's wars, and an overlord defends its vassals.
+/// A vassal declaring independence has already been freed, so it fights alone.
+fn on_war_call_vassals(
+    trigger: On<DiplomacyChanged>,
+    mut relations: Query<&mut Relations>,
+    vassals: Query<(Entity, &VassalOf)>,
+    countries: Query<&Country>,
+) {
+    let ev = trigger.event();
+
+    if ev.new_relation != Relation::War {
+        return;
+    }
+
+    let overlord_of = |country: Entity| vassals.get(country).ok().map(|(_, v)| v.overlord);
+
+    let side = |country: Entity| {
+        let head = overlord_of(country).unwrap_or(country);
+        let mut members: Vec<Entity> = vassals
+            .iter()
+            .filter(|(_, v)| v.overlord == head)
+            .map(|(vassal, _)| vassal)
+            .collect();
+        members.push(head);
+        members
+    };
+
+    let attackers = side(ev.declarer);
+    let defenders = side(ev.target);
+
+    for &attacker in &attackers {
+        for &defender in &defenders {
+            if attacker == defender {
+                continue;
+            }
+
+            let joins = (attacker, defender) != (ev.declarer, ev.target);
+            if let Ok(mut attacker_relations) = relations.get_mut(attacker) {
+                attacker_relations.set(defender, Relation::War);
+            }
+            if let Ok(mut defender_relations) = relations.get_mut(defender) {
+                defender_relations.set(attacker, Relation::War);
+            }
+
+            if joins && let (Ok(a), Ok(d)) = (countries.get(attacker), countries.get(defender)) {
+                println!(
+                    "{} joined the war against {} as vassal or overlord",
+                    a.name, d.name
+                );
+            }
+        }
+    }
+}
+
+/// Takes the receiver of an offer of vassalage as a vassal once it accepts.
+fn on_vassalage_proposal_answered(
+    trigger: On<ProposalAnswered>,
+    mut commands: Commands,
+    mut relations: Query<&mut Relations>,
+    vassals: Query<&VassalOf>,
+) {
+    let ev = trigger.event();
+    let proposal = &ev.proposal;
+
+    if !ev.accepted || !matches!(proposal.kind, ProposalKind::Vassalage) {
+        return;
+    }
+
+    if vassals.contains(proposal.from) || is_bound(&vassals, proposal.to) {
+        return;
+    }
+
+    let Ok([mut vassal_relations, mut overlord_relations]) =
+        relations.get_many_mut([proposal.to, proposal.from])
+    else {
+        return;
+    };
+    if vassal_relations.get(proposal.from) == Relation::War {
+        return;
+    }
+
+    make_vassal(
+        &mut commands,
+        (proposal.to, &mut vassal_relations),
+        (proposal.from, &mut overlord_relations),
+    );
+}
+
+fn ai_answer_vassalage_proposals(
+    mut commands: Commands,
+    mut proposals: ResMut<Proposals>,
+    ai_countries: Query<&Opinions, With<AIControlled>>,
+    strength: CountryStrength,
+) {
+    let offers = proposals.take(|proposal| {
+        matches!(proposal.kind, ProposalKind::Vassalage) && ai_countries.contains(proposal.to)
+    });
+
+    for proposal in offers {
+        let accepted = ai_countries.get(proposal.to).is_ok_and(|opinions| {
+            accepts_vassalage(
+                strength.of(proposal.to),
+                strength.of(proposal.from),
+                opinions.get(proposal.from),
+            )
+        });
+        commands.trigger(ProposalAnswered { proposal, accepted });
+    }
+}
+
+fn ai_offer_vassalage(
+    mut commands: Commands,
+    ai_countries: Query<(Entity, &Country), (With<AIControlled>, Without<VassalOf>)>,
+    candidates: Query<
+        (Entity, &Country, &Opinions, Has<AIControlled>),
+        (Without<VassalOf>, Without<RebelFaction>),
+    >,
+    vassals: Query<&VassalOf>,
+    mut relations: Query<&mut Relations>,
+    strength: CountryStrength,
+    mut proposals: ResMut<Proposals>,
+) {
+    let mut rng = rand::rng();
+    // New vassals only show up in `vassals` once the commands are applied, so
+    // those bound during this pass are tracked here.
+    let mut bound: HashSet<Entity> = HashSet::new();
+
+    for (country_entity, country) in &ai_countries {
+        if bound.contains(&country_entity) || !rng.random_bool(0.05) {
+            continue;
+        }
+
+        // Overlords keep their own vassals and are not taken as vassals themselves.
+        let targets: Vec<(Entity, &Country, &Opinions, bool)> = candidates
+            .iter()
+            .filter(|&(other, ..)| {
+                other != country_entity
+                    && !bound.contains(&other)
+                    && !vassals.iter().any(|v| v.overlord == other)
+                    && relations
+                        .get(country_entity)
+                        .is_ok_and(|r| r.get(other) == Relation::Peace)
+            })
+            .collect();
+
+        let Some(&(target, target_country, opinions, target_is_ai)) = targets.choose(&mut rng)
+        else {
+            continue;
+        };
+
+        // The player answers from the diplomatic inbox, but only gets offers worth considering.
+        if !target_is_ai {
+            if overshadows(strength.of(country_entity), strength.of(target)) {
+                proposals.send(country_entity, target, ProposalKind::Vassalage);
+            }
+            continue;
+        }
+
+        if !accepts_vassalage(
+            strength.of(target),
+            strength.of(country_entity),
+            opinions.get(country_entity),
+        ) {
+            continue;
+        }
+
+        let Ok([mut target_relations, mut own_relations]) =
+            relations.get_many_mut([target, country_entity])
+        else {
+            continue;
+        };
+        make_vassal(
+            &mut commands,
+            (target, &mut target_relations),
+            (country_entity, &mut own_relations),
+        );
+        bound.insert(country_entity);
+        bound.insert(target);
+        println!("{} made {} its vassal", country.name, target_country.name);
+    }
+}
+
+fn ai_seek_independence(
+    mut commands: Commands,
+    vassals: Query<(Entity, &Country, &VassalOf), With<AIControlled>>,
+    mut relations: Query<&mut Relations>,
+    strength: CountryStrength,
+) {
+    let mut rng = rand::rng();
+
+    for (vassal, country, vassal_of) in &vassals {
+        let overlord = vassal_of.overlord;
+        let overlord_strength = strength.of(overlord) as f32;
+
+        if (strength.of(vassal) as f32) < overlord_strength * INDEPENDENCE_STRENGTH_RATIO
+            || !rng.random_bool(0.2)
+        {
+            continue;
+        }
+
+        let Ok([mut vassal_relations, mut overlord_relations]) =
+            relations.get_many_mut([vassal, overlord])
+        else {
+            continue;
+        };
+        declare_independence(
+            &mut commands,
+            (vassal, &mut vassal_relations),
+            (overlord, &mut overlord_relations),
+        );
+        println!("{} declared independence", country.name);
+    }
+}
+
+/// Frees `vassal` from `overlord` by going to war with it.
+pub fn declare_independence(
+    commands: &mut Commands,
+    vassal: (Entity, &mut Relations),
+    overlord: (Entity, &mut Relations),
+) {
+    let (vassal, vassal_relations) = vassal;
+    let (overlord, overlord_relations) = overlord;
+
+    commands.entity(vassal).remove::<VassalOf>();
+    vassal_relations.set(overlord, Relation::War);
+    overlord_relations.set(vassal, Relation::War);
+
+    commands.trigger(DiplomacyChanged {
+        declarer: vassal,
+        target: overlord,
+        new_relation: Relation::War,
+    });
+}
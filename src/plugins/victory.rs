@@ -0,0 +1,404 @@
+// plugins/victory.rs
+use crate::components::army::Army;
+use crate::components::country::{Country, Grievances, RebelFaction, Relations, VassalOf};
+use crate::components::events::{PendingEvent, QueuedEvents};
+use crate::components::opinion::Opinions;
+use crate::components::player::{ControlsCountry, LocalPlayer};
+use crate::components::province::{Core, Occupied, OwnedBy, Province, TerrainType};
+use crate::plugins::claims::Claims;
+use crate::plugins::coalitions::Coalitions;
+use crate::plugins::military_access::MilitaryAccess;
+use crate::plugins::proposals::Proposals;
+use crate::plugins::truces::Truces;
+use crate::plugins::turn::TurnResolutionSet;
+use crate::plugins::vassals::CountryStrength;
+use crate::plugins::war_score::Wars;
+use crate::states::{AppState, GamePhase};
+use anyhow::Context;
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Gold worth one point of score.
+const GOLD_PER_SCORE: u32 = 100;
+
+pub struct VictoryPlugin;
+
+impl Plugin for VictoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VictoryConditions>()
+            .init_resource::<GameStats>()
+            .add_systems(OnEnter(AppState::LoadingNewGame), load_victory_conditions)
+            .add_systems(OnEnter(AppState::LoadingSavedGame), load_victory_conditions)
+            .add_systems(OnEnter(AppState::InGame), track_province_owners)
+            .add_systems(OnEnter(AppState::GameOver), end_game)
+            .add_systems(
+                Update,
+                (
+                    count_turn,
+                    track_province_owners,
+                    check_game_over,
+                    eliminate_countries,
+                )
+                    .chain()
+                    .in_set(TurnResolutionSet::End),
+            );
+    }
+}
+
+/// A way to win the game. The game ends as soon as any country meets one.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum VictoryCondition {
+    /// Every other country has been eliminated or made a vassal.
+    Conquest,
+    /// Own at least this share of all land provinces.
+    ProvinceShare(f32),
+    /// Have the highest score once this many turns have been played.
+    ScoreAfterTurns(u32),
+}
+
+/// The victory conditions in play, read from assets/data/victory.ron.
+#[derive(Resource, Clone, Debug)]
+pub struct VictoryConditions(pub Vec<VictoryCondition>);
+
+impl Default for VictoryConditions {
+    fn default() -> Self {
+        VictoryConditions(vec![VictoryCondition::Conquest])
+    }
+}
+
+/// A country's running tallies over the whole game.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct CountryStats {
+    pub battles_won: u32,
+    pub battles_lost: u32,
+    pub provinces_gained: u32,
+    pub provinces_lost: u32,
+}
+
+/// The turn count and what every country has done so far.
+#[derive(Resource, Default)]
+pub struct GameStats {
+    pub turn: u32,
+    pub countries: HashMap<Entity, CountryStats>,
+    /// Names of eliminated countries and the turn each one fell.
+    pub eliminated: Vec<(String, u32)>,
+    /// Each province's owner as of the last check, to notice provinces changing hands.
+    owners: HashMap<Entity, Entity>,
+}
+
+impl GameStats {
+    pub fn of(&self, country: Entity) -> CountryStats {
+        self.countries.get(&country).copied().unwrap_or_default()
+    }
+
+    pub fn record_battle(&mut self, winner: Entity, loser: Entity) {
+        self.countries.entry(winner).or_default().battles_won += 1;
+        self.countries.entry(loser).or_default().battles_lost += 1;
+    }
+}
+
+/// A country's score: its land and armies, plus its treasury.
+pub fn score(strength: i32, gold: u32) -> i32 {
+    strength + (gold / GOLD_PER_SCORE) as i32
+}
+
+/// One country's line in the final standings.
+#[derive(Clone, Debug)]
+pub struct Standing {
+    pub name: String,
+    pub provinces: usize,
+    pub gold: u32,
+    pub score: i32,
+    pub stats: CountryStats,
+}
+
+/// How the game ended, kept for the game over screen.
+#[derive(Resource, Clone, Debug)]
+pub struct GameOutcome {
+    pub title: String,
+    pub reason: String,
+    pub turns: u32,
+    /// The player's country and its tallies, if someone was playing.
+    pub player: Option<(String, CountryStats)>,
+    /// Surviving countries, best score first.
+    pub standings: Vec<Standing>,
+    pub eliminated: Vec<(String, u32)>,
+}
+
+fn load_victory_conditions(mut commands: Commands) {
+    match load_victory_conditions_from_file() {
+        Ok(conditions) => commands.insert_resource(VictoryConditions(conditions)),
+        Err(err) => {
+            eprintln!("{:?}", err);
+            commands.insert_resource(VictoryConditions::default());
+        }
+    }
+}
+
+fn load_victory_conditions_from_file() -> anyhow::Result<Vec<VictoryCondition>> {
+    let file = std::fs::read_to_string("assets/data/victory.ron")?;
+    ron::from_str(&file).context("Failed to parse victory.ron")
+}
+
+fn count_turn(mut stats: ResMut<GameStats>) {
+    stats.turn += 1;
+}
+
+/// Counts provinces gained and lost since the last check, whether by peace or revolt.
+fn track_province_owners(mut stats: ResMut<GameStats>, provinces: Query<(Entity, &OwnedBy)>) {
+    for (province, owned_by) in &provinces {
+        let new_owner = owned_by.owner;
+        let Some(old_owner) = stats.owners.insert(province, new_owner) else {
+            continue;
+        };
+
+        if old_owner != new_owner {
+            stats
+                .countries
+                .entry(new_owner)
+                .or_default()
+                .provinces_gained += 1;
+            stats.countries.entry(old_owner).or_default().provinces_lost += 1;
+        }
+    }
+}
+
+/// Ends the game once a country meets a victory condition or the player has
+/// lost every province.
+fn check_game_over(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<AppState>>,
+    (conditions, stats): (Res<VictoryConditions>, Res<GameStats>),
+    countries: Query<(Entity, &Country, Option<&VassalOf>), Without<RebelFaction>>,
+    provinces: Query<(&Province, Option<&OwnedBy>)>,
+    strength: CountryStrength,
+    (local_player, player_query): (Option<Res<LocalPlayer>>, Query<&ControlsCountry>),
+) {
+    let player_country = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0);
+
+    let owned = |country: Entity| {
+        provinces
+            .iter()
+            .filter(|(_, owned_by)| owned_by.is_some_and(|o| o.owner == country))
+            .count()
+    };
+    let land = provinces
+        .iter()
+        .filter(|(province, _)| province.terrain != TerrainType::Water)
+        .count();
+    let country_score = |country: Entity, gold: u32| score(strength.of(country), gold);
+
+    let mut ending: Option<(Option<Entity>, String)> = None;
+
+    for condition in &conditions.0 {
+        if ending.is_some() {
+            break;
+        }
+
+        match *condition {
+            VictoryCondition::Conquest => {
+                let conqueror = countries.iter().find(|&(country, _, vassal_of)| {
+                    vassal_of.is_none()
+                        && owned(country) > 0
+                        && countries.iter().all(|(other, _, other_vassal_of)| {
+                            other == country
+                                || owned(other) == 0
+                                || other_vassal_of.is_some_and(|v| v.overlord == country)
+                        })
+                });
+                if let Some((country, c, _)) = conqueror {
+                    ending = Some((
+                        Some(country),
+                        format!("{} has conquered all its rivals", c.name),
+                    ));
+                }
+            }
+            VictoryCondition::ProvinceShare(share) => {
+                let dominant = countries.iter().find(|&(country, ..)| {
+                    land > 0 && owned(country) as f32 / land as f32 >= share
+                });
+                if let Some((country, c, _)) = dominant {
+                    ending = Some((
+                        Some(country),
+                        format!("{} holds {:.0}% of all land", c.name, share * 100.0),
+                    ));
+                }
+            }
+            VictoryCondition::ScoreAfterTurns(turns) => {
+                if stats.turn < turns {
+                    continue;
+                }
+                let leader = countries
+                    .iter()
+                    .max_by_key(|&(country, c, _)| country_score(country, c.gold));
+                if let Some((country, c, _)) = leader {
+                    ending = Some((
+                        Some(country),
+                        format!("{} has the highest score after {} turns", c.name, turns),
+                    ));
+                }
+            }
+        }
+    }
+
+    if ending.is_none()
+        && let Some(player_country) = player_country
+        && owned(player_country) == 0
+    {
+        let name = countries
+            .get(player_country)
+            .map_or("Our country".to_string(), |(_, c, _)| c.name.clone());
+        ending = Some((None, format!("{} has lost its last province", name)));
+    }
+
+    let Some((winner, reason)) = ending else {
+        return;
+    };
+
+    let title = match (player_country, winner) {
+        (None, _) => "Game Over",
+        (Some(player), Some(winner)) if player == winner => "Victory",
+        _ => "Defeat",
+    };
+
+    let mut standings: Vec<Standing> = countries
+        .iter()
+        .filter(|&(country, ..)| owned(country) > 0)
+        .map(|(country, c, _)| Standing {
+            name: c.name.clone(),
+            provinces: owned(country),
+            gold: c.gold,
+            score: country_score(country, c.gold),
+            stats: stats.of(country),
+        })
+        .collect();
+    standings.sort_by_key(|standing| std::cmp::Reverse(standing.score));
+
+    let player = player_country.and_then(|country| {
+        countries
+            .get(country)
+            .ok()
+            .map(|(_, c, _)| (c.name.clone(), stats.of(country)))
+    });
+
+    println!("Game over: {}", reason);
+
+    commands.insert_resource(GameOutcome {
+        title: title.to_string(),
+        reason,
+        turns: stats.turn,
+        player,
+        standings,
+        eliminated: stats.eliminated.clone(),
+    });
+    next_state.set(AppState::GameOver);
+}
+
+/// Everything other countries keep on record about one another.
+#[derive(SystemParam)]
+struct DiplomaticRecords<'w> {
+    wars: ResMut<'w, Wars>,
+    truces: ResMut<'w, Truces>,
+    access: ResMut<'w, MilitaryAccess>,
+    claims: ResMut<'w, Claims>,
+    proposals: ResMut<'w, Proposals>,
+    coalitions: ResMut<'w, Coalitions>,
+}
+
+impl DiplomaticRecords<'_> {
+    fn forget(&mut self, country: Entity) {
+        self.wars.wars.retain(|war| !war.sides.contains(&country));
+        self.truces
+            .truces
+            .retain(|truce| !truce.sides.contains(&country));
+        self.access
+            .grants
+            .retain(|&(granter, grantee)| granter != country && grantee != country);
+        self.claims
+            .claims
+            .retain(|&(claimant, _)| claimant != country);
+        self.claims
+            .fabrications
+            .retain(|fabrication| fabrication.claimant != country);
+        self.proposals
+            .pending
+            .retain(|proposal| proposal.from != country && proposal.to != country);
+        self.coalitions.remove_country(country);
+    }
+}
+
+/// Removes countries that own no provinces. Their armies disband, their
+/// vassals go free and every other country forgets them. The player's country
+/// is left for the game over screen.
+fn eliminate_countries(
+    mut commands: Commands,
+    countries: Query<(Entity, &Country), Without<RebelFaction>>,
+    mut ties: Query<(&mut Relations, &mut Opinions, &mut Grievances)>,
+    provinces: Query<(Entity, &OwnedBy, Option<&Occupied>, Option<&Core>)>,
+    (armies, vassals, player): (
+        Query<(Entity, &Army)>,
+        Query<(Entity, &VassalOf)>,
+        (Option<Res<LocalPlayer>>, Query<&ControlsCountry>),
+    ),
+    mut records: DiplomaticRecords,
+    mut stats: ResMut<GameStats>,
+) {
+    let (local_player, player_query) = player;
+    let player_country = local_player
+        .and_then(|lp| player_query.get(lp.0).ok())
+        .map(|controls| controls.0);
+
+    let eliminated: Vec<(Entity, &Country)> = countries
+        .iter()
+        .filter(|&(country, _)| Some(country) != player_country)
+        .filter(|&(country, _)| !provinces.iter().any(|(_, o, ..)| o.owner == country))
+        .collect();
+
+    for (country, c) in eliminated {
+        for (mut relations, mut opinions, mut grievances) in &mut ties {
+            relations.relations.remove(&country);
+            opinions.of.remove(&country);
+            grievances.against.remove(&country);
+        }
+
+        for (vassal, vassal_of) in &vassals {
+            if vassal_of.overlord == country {
+                commands.entity(vassal).remove::<VassalOf>();
+            }
+        }
+
+        for (province, _, occupied, core) in &provinces {
+            if occupied.is_some_and(|occ| occ.occupier == country) {
+                commands.entity(province).remove::<Occupied>();
+            }
+            if core.is_some_and(|core| core.country == country) {
+                commands.entity(province).remove::<Core>();
+            }
+        }
+
+        for (army_entity, army) in &armies {
+            if army.owner == country {
+                commands.entity(army_entity).despawn();
+            }
+        }
+
+        records.forget(country);
+        stats.countries.remove(&country);
+        let turn = stats.turn;
+        stats.eliminated.push((c.name.clone(), turn));
+
+        commands.entity(country).despawn();
+        println!("{} has been eliminated", c.name);
+    }
+}
+
+/// Stops the turn where it is; nothing is played after the game ends.
+fn end_game(mut commands: Commands, mut next_phase: ResMut<NextState<GamePhase>>) {
+    commands.remove_resource::<PendingEvent>();
+    commands.insert_resource(QueuedEvents::default());
+    next_phase.set(GamePhase::PlayerTurn);
+}
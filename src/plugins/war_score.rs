@@ -0,0 +1,271 @@
+// plugins/war_score.rs
+use crate::components::country::{Country, RebelFaction, Relation, Relations};
+use crate::components::province::{Occupied, OwnedBy, Province};
+use crate::plugins::peace::province_value;
+use crate::plugins::turn::TurnResolutionSet;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+/// War score for every net battle won, and the most battles can be worth.
+const BATTLE_SCORE: i32 = 5;
+const MAX_BATTLE_SCORE: i32 = 40;
+/// Score gained each turn by the side holding more of the other's land.
+const MAX_TICKING_SCORE: i32 = 25;
+
+const MAX_EXHAUSTION: f32 = 100.0;
+const EXHAUSTION_PER_TURN: f32 = 1.0;
+const EXHAUSTION_PER_BATTLE_LOST: f32 = 3.0;
+const EXHAUSTION_PER_OCCUPIED_PROVINCE: f32 = 0.5;
+
+pub struct WarScorePlugin;
+
+impl Plugin for WarScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wars>()
+            .add_systems(Update, track_wars.in_set(TurnResolutionSet::End));
+    }
+}
+
+/// How one side stands in a war, in points from -100 to 100.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WarScore {
+    pub occupation: i32,
+    pub battles: i32,
+    pub ticking: i32,
+}
+
+impl WarScore {
+    pub fn total(&self) -> i32 {
+        (self.occupation + self.battles + self.ticking).clamp(-100, 100)
+    }
+}
+
+/// One war between two countries. Per-side values are indexed like `sides`,
+/// shared values are seen from `sides[0]`.
+#[derive(Clone, Debug)]
+pub struct War {
+    pub sides: [Entity; 2],
+    pub turns: u32,
+    pub battles_won: [u32; 2],
+    pub occupation: i32,
+    pub ticking: i32,
+    pub exhaustion: [f32; 2],
+}
+
+impl War {
+    pub fn new(a: Entity, b: Entity) -> Self {
+        Self {
+            sides: [a, b],
+            turns: 0,
+            battles_won: [0, 0],
+            occupation: 0,
+            ticking: 0,
+            exhaustion: [0.0, 0.0],
+        }
+    }
+
+    fn index(&self, side: Entity) -> usize {
+        if self.sides[0] == side { 0 } else { 1 }
+    }
+
+    pub fn score(&self, side: Entity) -> WarScore {
+        let us = self.index(side);
+        let sign = if us == 0 { 1 } else { -1 };
+        let net_battles = self.battles_won[us] as i32 - self.battles_won[1 - us] as i32;
+
+        WarScore {
+            occupation: self.occupation * sign,
+            battles: (net_battles * BATTLE_SCORE).clamp(-MAX_BATTLE_SCORE, MAX_BATTLE_SCORE),
+            ticking: self.ticking * sign,
+        }
+    }
+
+    pub fn battles_won(&self, side: Entity) -> u32 {
+        self.battles_won[self.index(side)]
+    }
+
+    pub fn battles_lost(&self, side: Entity) -> u32 {
+        self.battles_won[1 - self.index(side)]
+    }
+
+    pub fn exhaustion(&self, side: Entity) -> f32 {
+        self.exhaustion[self.index(side)]
+    }
+}
+
+/// Every ongoing war between two countries.
+#[derive(Resource, Default)]
+pub struct Wars {
+    pub wars: Vec<War>,
+}
+
+impl Wars {
+    pub fn get(&self, a: Entity, b: Entity) -> Option<&War> {
+        self.wars
+            .iter()
+            .find(|war| war.sides.contains(&a) && war.sides.contains(&b))
+    }
+
+    fn get_or_start(&mut self, a: Entity, b: Entity) -> &mut War {
+        let position = self
+            .wars
+            .iter()
+            .position(|war| war.sides.contains(&a) && war.sides.contains(&b));
+
+        match position {
+            Some(index) => &mut self.wars[index],
+            None => {
+                self.wars.push(War::new(a, b));
+                self.wars.last_mut().unwrap()
+            }
+        }
+    }
+
+    /// The war score of `side` against `enemy`; zero if they are not at war.
+    pub fn score(&self, side: Entity, enemy: Entity) -> WarScore {
+        self.get(side, enemy)
+            .map_or(WarScore::default(), |war| war.score(side))
+    }
+
+    pub fn exhaustion(&self, side: Entity, enemy: Entity) -> f32 {
+        self.get(side, enemy)
+            .map_or(0.0, |war| war.exhaustion(side))
+    }
+
+    pub fn record_battle(&mut self, winner: Entity, loser: Entity) {
+        let war = self.get_or_start(winner, loser);
+        let (w, l) = (war.index(winner), war.index(loser));
+        war.battles_won[w] += 1;
+        war.exhaustion[l] = (war.exhaustion[l] + EXHAUSTION_PER_BATTLE_LOST).min(MAX_EXHAUSTION);
+    }
+}
+
+/// Starts and ends wars to match relations, then ticks every war a turn on.
+fn track_wars(
+    mut wars: ResMut<Wars>,
+    countries: Query<(Entity, &Relations), (With<Country>, Without<RebelFaction>)>,
+    provinces: Query<(&Province, &OwnedBy, Option<&Occupied>)>,
+) {
+    let at_war = |a: Entity, b: Entity| {
+        countries
+            .get(a)
+            .is_ok_and(|(_, relations)| relations.get(b) == Relation::War)
+    };
+
+    wars.wars.retain(|war| at_war(war.sides[0], war.sides[1]));
+
+    for (country, relations) in &countries {
+        for (&other, &relation) in &relations.relations {
+            if relation == Relation::War && country < other && countries.contains(other) {
+                wars.get_or_start(country, other);
+            }
+        }
+    }
+
+    let mut land: HashMap<Entity, i32> = HashMap::new();
+    let mut taken: HashMap<(Entity, Entity), (i32, u32)> = HashMap::new();
+
+    for (province, owned_by, occupied) in &provinces {
+        let value = province_value(province);
+        *land.entry(owned_by.owner).or_insert(0) += value;
+
+        if let Some(occupied) = occupied {
+            let entry = taken
+                .entry((occupied.occupier, owned_by.owner))
+                .or_insert((0, 0));
+            entry.0 += value;
+            entry.1 += 1;
+        }
+    }
+
+    let share = |occupier: Entity, owner: Entity| {
+        let (value, _) = taken.get(&(occupier, owner)).copied().unwrap_or_default();
+        let total = land.get(&owner).copied().unwrap_or(0);
+        if total > 0 { value * 100 / total } else { 0 }
+    };
+    let provinces_lost = |occupier: Entity, owner: Entity| {
+        taken.get(&(occupier, owner)).map_or(0, |&(_, count)| count)
+    };
+
+    for war in &mut wars.wars {
+        let [a, b] = war.sides;
+
+        war.turns += 1;
+        war.occupation = share(a, b) - share(b, a);
+
+        // Whoever holds more of the other's land slowly wins the war by holding on.
+        war.ticking =
+            (war.ticking + war.occupation.signum()).clamp(-MAX_TICKING_SCORE, MAX_TICKING_SCORE);
+
+        for (index, (side, enemy)) in [(a, b), (b, a)].into_iter().enumerate() {
+            let exhaustion = EXHAUSTION_PER_TURN
+                + provinces_lost(enemy, side) as f32 * EXHAUSTION_PER_OCCUPIED_PROVINCE;
+            war.exhaustion[index] = (war.exhaustion[index] + exhaustion).min(MAX_EXHAUSTION);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::misc::test_entities;
+
+    #[test]
+    fn score_is_mirrored_between_the_sides() {
+        let countries = test_entities(2);
+        let (a, b) = (countries[0], countries[1]);
+        let mut war = War::new(a, b);
+        war.battles_won = [3, 1];
+        war.occupation = 12;
+        war.ticking = 4;
+
+        let ours = war.score(a);
+        let theirs = war.score(b);
+        assert_eq!(ours.battles, 2 * BATTLE_SCORE);
+        assert_eq!(theirs.battles, -2 * BATTLE_SCORE);
+        assert_eq!((ours.occupation, theirs.occupation), (12, -12));
+        assert_eq!((ours.ticking, theirs.ticking), (4, -4));
+        assert_eq!(ours.total(), -theirs.total());
+    }
+
+    #[test]
+    fn battle_score_is_clamped() {
+        let countries = test_entities(2);
+        let (a, b) = (countries[0], countries[1]);
+        let mut wars = Wars::default();
+        for _ in 0..20 {
+            wars.record_battle(a, b);
+        }
+
+        assert_eq!(wars.score(a, b).battles, MAX_BATTLE_SCORE);
+        assert_eq!(wars.score(b, a).battles, -MAX_BATTLE_SCORE);
+    }
+
+    #[test]
+    fn recorded_battles_count_for_the_winner_either_way_round() {
+        let countries = test_entities(2);
+        let (a, b) = (countries[0], countries[1]);
+        let mut wars = Wars::default();
+        wars.record_battle(a, b);
+        wars.record_battle(b, a);
+        wars.record_battle(b, a);
+
+        assert_eq!(wars.wars.len(), 1);
+        let war = wars
+            .get(a, b)
+            .map(|war| (war.battles_won(a), war.battles_lost(a)));
+        assert_eq!(war, Some((1, 2)));
+        assert_eq!(wars.score(b, a).battles, BATTLE_SCORE);
+        assert_eq!(wars.exhaustion(a, b), 2.0 * EXHAUSTION_PER_BATTLE_LOST);
+        assert_eq!(wars.exhaustion(b, a), EXHAUSTION_PER_BATTLE_LOST);
+    }
+
+    #[test]
+    fn no_war_scores_nothing() {
+        let countries = test_entities(2);
+        let wars = Wars::default();
+
+        assert_eq!(wars.score(countries[0], countries[1]).total(), 0);
+        assert_eq!(wars.exhaustion(countries[0], countries[1]), 0.0);
+    }
+}
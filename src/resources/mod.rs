@@ -1,3 +1,5 @@
 mod map_size;
+mod trade_prices;
 
 pub use map_size::MapSize;
+pub use trade_prices::TradePrices;
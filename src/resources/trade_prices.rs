@@ -0,0 +1,19 @@
+use crate::components::trade::{TradeGood, TradeGoodDef};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+#[derive(Resource, Default)]
+pub struct TradePrices(pub HashMap<TradeGood, u32>);
+
+impl TradePrices {
+    pub fn from_defs(defs: &[TradeGoodDef]) -> Self {
+        Self(defs.iter().map(|def| (def.good, def.price)).collect())
+    }
+
+    pub fn price(&self, good: TradeGood) -> u32 {
+        self.0
+            .get(&good)
+            .copied()
+            .unwrap_or_else(|| good.default_price())
+    }
+}
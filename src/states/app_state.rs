@@ -8,4 +8,5 @@ pub enum AppState {
     LoadingSavedGame,
     CountrySelection,
     InGame,
+    GameOver,
 }